@@ -0,0 +1,37 @@
+//! Compares `rotate_left_cached` with `as_mut_slice().rotate_left`.
+//!
+//! Run with `cargo bench --bench rotate`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use vector_array::VecArray;
+
+const ITERATIONS: u32 = 10_000;
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<const CAP: usize>() {
+    let mut vec: VecArray<u64, CAP> = (0..CAP as u64).collect();
+
+    for mid in [1, CAP / 4, CAP / 2] {
+        let cached = time(|| black_box(&mut vec).rotate_left_cached(black_box(mid)));
+        let slice = time(|| {
+            black_box(&mut vec)
+                .as_mut_slice()
+                .rotate_left(black_box(mid))
+        });
+        println!("CAP {CAP:>6}, mid {mid:>6}: rotate_left_cached {cached:>10?}, slice rotate_left {slice:>10?}");
+    }
+}
+
+fn main() {
+    bench::<64>();
+    bench::<1024>();
+    bench::<16384>();
+}
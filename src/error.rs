@@ -10,3 +10,75 @@ impl fmt::Display for ArrTooSmall {
         write!(f, "{self:?}")
     }
 }
+
+/// Returned when an operation needs more room than the VecArray has
+#[derive(Error, Debug, Copy, Clone, Default, PartialEq)]
+pub struct CapacityError {
+    /// The length the operation would have needed
+    pub attempted: usize,
+    /// The capacity of the VecArray
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "VecArray needs room for {} elements but has a capacity of {}",
+            self.attempted, self.capacity
+        )
+    }
+}
+
+impl From<CapacityError> for ArrTooSmall {
+    fn from(_: CapacityError) -> Self {
+        ArrTooSmall
+    }
+}
+
+/// Returned when bytes can't be reinterpreted as the elements of a VecArray
+#[cfg(feature = "bytemuck")]
+#[derive(Error, Debug, Copy, Clone, PartialEq)]
+pub enum FromBytesError {
+    /// The number of bytes is not a multiple of the element size
+    SizeMismatch,
+    /// There are more elements than fit in the VecArray
+    TooLong(CapacityError),
+}
+
+#[cfg(feature = "bytemuck")]
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeMismatch => write!(f, "Byte length is not a multiple of the element size"),
+            Self::TooLong(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Returned when a push is rejected, gives the rejected value back
+#[derive(Error, Debug, Copy, Clone, PartialEq)]
+pub enum PushError<T> {
+    /// The VecArray is full
+    Full(T),
+    /// The value is greater than the allowed maximum
+    OutOfBounds(T),
+}
+
+impl<T> PushError<T> {
+    /// Returns the rejected value
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(value) | Self::OutOfBounds(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => write!(f, "VecArray is full"),
+            Self::OutOfBounds(_) => write!(f, "Value is greater than the allowed maximum"),
+        }
+    }
+}
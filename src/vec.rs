@@ -1,6 +1,15 @@
-use crate::error::ArrTooSmall;
+#[cfg(feature = "bytemuck")]
+use crate::error::FromBytesError;
+use crate::error::{ArrTooSmall, CapacityError, PushError};
+use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Index, IndexMut};
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ops::{
+    Add, BitAnd, BitOr, BitXor, Bound, Deref, DerefMut, Index, IndexMut, Mul, MulAssign, Range,
+    RangeBounds, Sub,
+};
 use std::slice::IterMut;
 
 #[cfg(test)]
@@ -16,14 +25,14 @@ mod test;
 /// vec.push(9).unwrap();
 /// assert_eq!(vec[0], 9);
 /// ```
-#[derive(Clone)]
 pub struct VecArray<T, const CAP: usize> {
-    arr: [T; CAP],
+    // only the first len elements are initialized
+    arr: [MaybeUninit<T>; CAP],
     len: usize,
 }
 
 pub struct IntoIter<T, const CAP: usize> {
-    arr: [T; CAP],
+    arr: [MaybeUninit<T>; CAP],
     len: usize,
     itr: usize,
 }
@@ -32,324 +41,2407 @@ pub struct IntoIter<T, const CAP: usize> {
 pub struct Iter<'a, T> {
     arr: &'a [T],
     itr: usize,
+    end: usize,
+}
+
+/// A draining iterator created by [`VecArray::drain`].
+///
+/// The remaining elements are dropped and the tail is moved back when this is dropped.
+pub struct Drain<'a, T, const CAP: usize> {
+    // vec.len is set to start while draining, so a leaked Drain only forgets elements
+    vec: &'a mut VecArray<T, CAP>,
+    start: usize,
+    itr: usize,
+    end: usize,
+    old_len: usize,
 }
 
 /// Does the same as ::new
-impl<T, const CAP: usize> Default for VecArray<T, CAP>
-where
-    T: Default,
-{
+impl<T, const CAP: usize> Default for VecArray<T, CAP> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T, const CAP: usize> VecArray<T, CAP>
-where
-    T: Default,
-{
-    /// Initializes all elements with defaults (does not increment length)
+impl<T, const CAP: usize> VecArray<T, CAP> {
+    /// The pointer arithmetic used by this type is only sound if the whole array fits in isize::MAX bytes.
+    /// Evaluated by every constructor, so an oversized CAP fails at compile time.
     ///
     /// # Example
-    /// ```
+    /// ```compile_fail
     /// use vector_array::vec::VecArray;
     ///
-    /// let mut vec: VecArray<_, 10> = VecArray::new();
-    /// vec.push(9).unwrap();
-    /// assert_eq!(vec[0], 9);
+    /// let vec: VecArray<u64, { usize::MAX / 4 }> = VecArray::new_no_default();
     /// ```
     ///
-    /// Use ::new_no_default if type doesn't implement default
-    ///
-    pub fn new() -> Self {
-        let mut slf = Self::new_no_default();
-        slf.arr
-            .iter_mut()
-            .for_each(|x| unsafe { ::std::ptr::write(x as *mut T, Default::default()) });
-        slf
+    const CAP_FITS: () = assert!(
+        match CAP.checked_mul(::std::mem::size_of::<T>()) {
+            Some(bytes) => bytes <= isize::MAX as usize,
+            None => false,
+        },
+        "VecArray capacity exceeds isize::MAX bytes"
+    );
+
+    /// Forces evaluation of CAP_FITS, called by every constructor
+    #[inline]
+    const fn assert_cap_fits() {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAP_FITS;
     }
-}
 
-impl<T, const CAP: usize> VecArray<T, CAP> {
-    /// Creates a new VecArray. Use ::new if type has default especially if type contains pointers/references (think String, Box, etc)
+    /// Creates a new empty VecArray, the storage is left uninitialized.
+    /// Usable in const and static items.
     ///
     /// # Example
     /// ```
     /// use vector_array::vec::VecArray;
     ///
-    /// let mut vec: VecArray<_, 10> = VecArray::new_no_default();
+    /// let mut vec: VecArray<_, 10> = VecArray::new();
     /// vec.push(9).unwrap();
     /// assert_eq!(vec[0], 9);
-    /// ```
     ///
-    /// # Safety
-    /// There may be problems with drops if your type contains references for example.
-    /// There also may be problems if you try to index in to parts of the array which are no yet initialized but this is nearly impossible.
+    /// static EMPTY: VecArray<u8, 16> = VecArray::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
     ///
-    #[allow(clippy::uninit_assumed_init)]
-    pub fn new_no_default() -> Self {
+    pub const fn new() -> Self {
+        Self::assert_cap_fits();
         Self {
-            arr: unsafe { std::mem::MaybeUninit::uninit().assume_init() },
+            // an array of MaybeUninit doesn't need to be initialized
+            arr: unsafe { MaybeUninit::uninit().assume_init() },
             len: 0,
         }
     }
 
-    /// Creates a new VecArray. Use when type doesnt implement default and (drop) safety is a problem.
+    /// Moves all elements into a VecArray with a different capacity.
     ///
-    pub fn new_arr(arr: [T; CAP], len: usize) -> Self {
-        Self { arr, len }
-    }
-
-    /// Pushes an element.
+    /// # Panics
+    /// If length is greater than NEW
     ///
     /// # Example
     /// ```
-    /// use vector_array::vec::VecArray;
+    /// use vector_array::{vec_arr, VecArray};
     ///
-    /// let mut vec: VecArray<_, 10> = VecArray::new();
-    /// vec.push(9).unwrap();
-    /// assert_eq!(vec[0], 9);
+    /// let vec: VecArray<_, 3> = vec_arr![1, 2, 3];
+    /// let vec: VecArray<_, 10> = vec.reshape();
+    /// assert_eq!(vec.capacity(), 10);
+    /// assert_eq!(vec, vec![1, 2, 3]);
     /// ```
-    pub fn push(&mut self, value: T) -> Result<(), ArrTooSmall> {
-        if self.len < CAP {
-            unsafe {
-                ::std::ptr::write(&mut self.arr[self.len] as *mut T, value);
-            }
-            self.len += 1;
-            Ok(())
-        } else {
-            Err(ArrTooSmall)
+    ///
+    pub fn reshape<const NEW: usize>(self) -> VecArray<T, NEW> {
+        let len = self.len;
+        if len > NEW {
+            panic!("Length (is {len}) should be <= new capacity (is {NEW})");
+        }
+
+        let mut vec = VecArray::new();
+        for x in self {
+            // infallible
+            let _ = vec.push(x);
         }
+        vec
     }
 
-    /// Removes the last element
+    /// Same as ::new, from when ::new still required T: Default
     ///
     /// # Example
     /// ```
     /// use vector_array::vec::VecArray;
     ///
-    /// let mut vec: VecArray<_, 10> = VecArray::new();
+    /// let mut vec: VecArray<_, 10> = VecArray::new_no_default();
     /// vec.push(9).unwrap();
-    /// assert_eq!(vec.pop(), Some(9));
+    /// assert_eq!(vec[0], 9);
     /// ```
     ///
-    /// # Safety
-    /// Returns memory which will realistically wont be used anymore
-    ///
-    pub fn pop(&mut self) -> Option<T> {
-        if self.len == 0 {
-            None
-        } else {
-            self.len -= 1;
-            Some(unsafe { ::std::ptr::read(&self.arr[self.len] as *const T) })
-        }
+    pub const fn new_no_default() -> Self {
+        Self::new()
     }
 
-    /// Removes an element.
+    /// Creates a new VecArray from an array of which the first `len` elements are used.
+    /// The elements past `len` are dropped.
     ///
     /// # Panics
-    /// If index is greater than or equal to length
+    /// If len is greater than CAP, use ::try_new_arr to get an error instead
     ///
     /// # Example
     /// ```
     /// use vector_array::vec::VecArray;
-    /// let mut vec: VecArray<_, 10> = VecArray::new();
-    /// vec.push(9).unwrap();
-    /// vec.remove(0);
-    /// assert!(vec.is_empty());
+    ///
+    /// let vec = VecArray::new_arr([1, 2, 3], 2);
+    /// assert_eq!(vec, vec![1, 2]);
     /// ```
     ///
-    /// # Safety
-    /// Copied from Vec source code
+    pub fn new_arr(arr: [T; CAP], len: usize) -> Self {
+        assert!(len <= CAP, "len should be <= CAP");
+        let mut slf = Self::from_const(arr);
+        slf.truncate(len);
+        slf
+    }
+
+    /// Creates a full VecArray (length is CAP) from an array, usable in const and static items.
     ///
-    pub fn remove(&mut self, index: usize) -> T {
-        let len = self.len;
-        if index >= len {
-            panic!("Removal index (is {index}) should be < len (is {len})");
+    /// # Example
+    /// ```
+    /// use vector_array::vec::VecArray;
+    ///
+    /// const TABLE: VecArray<u8, 3> = VecArray::from_const([1, 2, 3]);
+    /// assert!(TABLE.is_full());
+    /// ```
+    ///
+    pub const fn from_const(arr: [T; CAP]) -> Self {
+        Self::assert_cap_fits();
+        let arr = ManuallyDrop::new(arr);
+        Self {
+            // [T; CAP] and [MaybeUninit<T>; CAP] have the same layout
+            arr: unsafe {
+                ::std::ptr::read(
+                    &arr as *const ManuallyDrop<[T; CAP]> as *const [MaybeUninit<T>; CAP],
+                )
+            },
+            len: CAP,
         }
+    }
 
-        // infallible
-        let ret;
-        unsafe {
-            // the place we are taking from.
-            let ptr = self.arr.as_mut_ptr().add(index);
-            // copy it out, unsafely having a copy of the value on
-            // the stack and in the vector at the same time.
-            ret = ::std::ptr::read(ptr);
-
-            // Shift everything down to fill in that spot.
-            ::std::ptr::copy(ptr.add(1), ptr, len - index - 1);
+    /// Creates a new VecArray like ::new_arr, but returns an error instead of panicking if `len` is greater than CAP.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::vec::VecArray;
+    /// use vector_array::error::ArrTooSmall;
+    ///
+    /// let vec = VecArray::try_new_arr([1, 2, 3], 2).unwrap();
+    /// assert_eq!(vec, vec![1, 2]);
+    /// assert_eq!(VecArray::try_new_arr([1, 2, 3], 4), Err(ArrTooSmall));
+    /// ```
+    ///
+    pub fn try_new_arr(arr: [T; CAP], len: usize) -> Result<Self, ArrTooSmall> {
+        if len > CAP {
+            Err(ArrTooSmall)
+        } else {
+            Ok(Self::new_arr(arr, len))
         }
-        self.len -= 1;
-        ret
     }
 
-    //// Inserts an element at position index within the vector, shifting all elements after it to the right.
+    /// Splits the vec into its raw storage and length without dropping anything.
     ///
-    /// # Panics
-    /// If index is greater than or equal to length or new length is greater than CAP
+    /// The first `len` elements of the returned array are initialized, the rest may not be.
     ///
     /// # Example
     /// ```
     /// use vector_array::{vec_arr, VecArray};
     ///
-    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
-    /// vec.insert(1, 4);
-    /// assert_eq!(vec, vec![1, 4, 2, 3]);
-    /// vec.insert(2, 5);
-    /// assert_eq!(vec, vec![1, 4, 5, 2, 3]);
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let (arr, len) = vec.into_parts();
+    /// let vec: VecArray<_, 10> = unsafe { VecArray::from_parts(arr, len) };
+    /// assert_eq!(vec, vec![1, 2, 3]);
     /// ```
     ///
+    pub fn into_parts(self) -> ([MaybeUninit<T>; CAP], usize) {
+        let slf = ManuallyDrop::new(self);
+        (unsafe { ::std::ptr::read(&slf.arr) }, slf.len)
+    }
+
+    /// Rebuilds a vec from its raw storage and length, the counterpart to ::into_parts.
+    ///
     /// # Safety
-    /// Copied from Vec source code
+    /// `len` must be less than or equal to CAP and the first `len` elements of `arr` must be initialized.
+    /// Ownership of those elements moves into the vec, so they must not be used or dropped elsewhere.
     ///
-    pub fn insert(&mut self, index: usize, element: T) {
-        if self.len + 1 > CAP {
-            panic!("Array too small")
-        }
-
-        if index >= self.len {
-            panic!("Index out of bounds");
-        }
+    pub unsafe fn from_parts(arr: [MaybeUninit<T>; CAP], len: usize) -> Self {
+        Self::assert_cap_fits();
+        Self { arr, len }
+    }
 
-        unsafe {
-            let ptr = self.arr.as_mut_ptr().add(index);
-            ::std::ptr::copy(ptr, ptr.add(1), self.len - index);
-            ::std::ptr::write(ptr, element);
+    /// Collects an iterator of options, returns None if any item is None or if there are more than CAP items.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
+    ///
+    /// let vec = VecArray::<_, 10>::try_collect_options([Some(1), Some(2)]);
+    /// assert_eq!(vec.unwrap(), vec![1, 2]);
+    ///
+    /// let vec = VecArray::<_, 10>::try_collect_options([Some(1), None]);
+    /// assert!(vec.is_none());
+    /// ```
+    ///
+    pub fn try_collect_options<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Option<T>>,
+    {
+        let mut slf = Self::new_no_default();
+        for x in iter {
+            slf.push(x?).ok()?;
         }
-        self.len += 1;
+        Some(slf)
     }
 
-    /// Removes an element from the vector and returns it.
-    ///
-    /// The removed element is replaced by the last element of the vector.
+    /// Collects an iterator, returns an error if it yields more than CAP items.
     ///
-    /// This does not preserve ordering, but is *O*(1).
-    /// If you need to preserve the element order, use `remove` instead.
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
     ///
-    /// # Panics
-    /// Panics if `index` is out of bounds.
+    /// let vec = VecArray::<_, 3>::try_from_iter(0..3);
+    /// assert_eq!(vec.unwrap(), vec![0, 1, 2]);
     ///
-    /// # Examples
+    /// let vec = VecArray::<_, 3>::try_from_iter(0..4);
+    /// assert!(vec.is_err());
     /// ```
-    /// use vector_array::{vec_arr, VecArray};
     ///
-    /// let mut v: VecArray<_, 10> = vec_arr!["foo", "bar", "baz", "qux"];
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, ArrTooSmall> {
+        let mut slf = Self::new_no_default();
+        for x in iter {
+            slf.push(x)?;
+        }
+        Ok(slf)
+    }
+
+    /// Moves the elements of the Vec into a VecArray, gives the Vec back if it is longer than CAP.
     ///
-    /// assert_eq!(v.swap_remove(1), "bar");
-    /// assert_eq!(v, &["foo", "qux", "baz"][..]);
+    /// `TryFrom<Vec<T>>` can't be implemented because it would conflict with `From<Vec<T>>`.
     ///
-    /// assert_eq!(v.swap_remove(0), "foo");
-    /// assert_eq!(v, &["baz", "qux"][..]);
+    /// # Example
     /// ```
+    /// use vector_array::VecArray;
     ///
-    pub fn swap_remove(&mut self, index: usize) -> T {
-        if index >= self.len {
-            panic!(
-                "swap_remove index (is {index}) should be < len (is {})",
-                self.len
-            );
+    /// let vec = VecArray::<_, 3>::try_from_vec(vec![1, 2, 3]).unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    ///
+    /// let vec = VecArray::<_, 2>::try_from_vec(vec![1, 2, 3]);
+    /// assert_eq!(vec.unwrap_err(), vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn try_from_vec(mut vec: Vec<T>) -> Result<Self, Vec<T>> {
+        let len = vec.len();
+        if len > CAP {
+            return Err(vec);
         }
 
-        self.len -= 1;
-
+        let mut slf = Self::new();
+        // the elements are now owned by slf, vec only frees its buffer
         unsafe {
-            let ptr = self.arr.as_mut_ptr();
-            let ret = ::std::ptr::read(ptr.add(index));
-            ::std::ptr::copy(ptr.add(self.len), ptr.add(index), 1);
-            ret
+            vec.set_len(0);
+            ::std::ptr::copy_nonoverlapping(vec.as_ptr(), slf.as_mut_ptr(), len);
         }
+        slf.len = len;
+        Ok(slf)
     }
 
-    /// Swaps two elements in the vec.
-    ///
-    /// # Panics
-    /// Panics if one of the indexes are out of bounds.
-    ///
-    /// # Examples
+    /// Creates a vec starting with `first` where each following element is computed from the previous one,
+    /// stops when `succ` returns None or the vec is full.
     ///
+    /// # Example
     /// ```
-    /// use vector_array::{vec_arr, VecArray};
+    /// use vector_array::VecArray;
     ///
-    /// let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
-    /// vec.swap(2, 3);
-    /// assert_eq!(vec, vec![0, 1, 3, 2]);
+    /// let vec = VecArray::<u32, 10>::successors(1, |&x| if x < 100 { Some(x * 10) } else { None });
+    /// assert_eq!(vec, vec![1, 10, 100]);
     /// ```
     ///
-    pub fn swap(&mut self, index1: usize, index2: usize) {
-        if index1 >= self.len || index2 >= self.len {
-            panic!("Index out of bounds");
+    pub fn successors<F: FnMut(&T) -> Option<T>>(first: T, mut succ: F) -> Self {
+        let mut slf = Self::new_no_default();
+        if slf.push(first).is_err() {
+            return slf;
         }
-        unsafe {
-            let ptr = self.arr.as_mut_ptr();
-            ::std::ptr::swap(ptr.add(index1), ptr.add(index2));
+        while !slf.is_full() {
+            match succ(&slf[slf.len - 1]) {
+                Some(x) => {
+                    // infallible
+                    let _ = slf.push(x);
+                }
+                None => break,
+            }
         }
+        slf
     }
 
-    /// Retains only the elements specified by the predicate.
+    /// Pushes an element.
     ///
-    /// In other words, remove all elements `e` for which `f(&e)` returns `false`.
-    /// This method operates in place, visiting each element exactly once in the
-    /// original order, and preserves the order of the retained elements.
+    /// # Example
+    /// ```
+    /// use vector_array::vec::VecArray;
     ///
-    /// # Examples
+    /// let mut vec: VecArray<_, 10> = VecArray::new();
+    /// vec.push(9).unwrap();
+    /// assert_eq!(vec[0], 9);
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<(), ArrTooSmall> {
+        if self.len < CAP {
+            self.arr[self.len].write(value);
+            self.len += 1;
+            Ok(())
+        } else {
+            Err(ArrTooSmall)
+        }
+    }
+
+    /// Pushes an element, on overflow the rejected value is handed to `err` to build the error.
+    ///
+    /// # Example
     /// ```
     /// use vector_array::{vec_arr, VecArray};
     ///
-    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
-    /// vec.retain(|&x| x % 2 == 0);
-    /// assert_eq!(vec, vec![2, 4]);
+    /// #[derive(Debug, PartialEq)]
+    /// struct Full(u32);
+    ///
+    /// let mut vec: VecArray<_, 1> = vec_arr![1];
+    /// assert_eq!(vec.push_or(2, Full), Err(Full(2)));
     /// ```
     ///
-    pub fn retain<F>(&mut self, mut f: F)
-    where
-        F: FnMut(&T) -> bool,
-    {
-        self.retain_mut(|x| f(x));
+    pub fn push_or<E>(&mut self, value: T, err: impl FnOnce(T) -> E) -> Result<(), E> {
+        if self.is_full() {
+            Err(err(value))
+        } else {
+            // infallible
+            let _ = self.push(value);
+            Ok(())
+        }
     }
 
-    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
-    ///
-    /// In other words, remove all elements `e` for which `f(&e)` returns `false`.
-    /// This method operates in place, visiting each element exactly once in the
-    /// original order, and preserves the order of the retained elements.
+    /// Pushes an element, on overflow the value is dropped, `overflow_count` is incremented and false is returned.
     ///
-    /// # Examples
+    /// # Example
     /// ```
-    /// use vector_array::{vec_arr, VecArray};
+    /// use vector_array::VecArray;
     ///
-    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
-    /// vec.retain_mut(|x| if *x <= 3 {
-    ///     *x += 1;
-    ///     true
-    /// } else {
-    ///     false
-    /// });
-    /// assert_eq!(vec, vec![2, 3, 4]);
+    /// let mut dropped = 0;
+    /// let mut vec: VecArray<_, 1> = VecArray::new();
+    /// assert!(vec.push_tracking(1, &mut dropped));
+    /// assert!(!vec.push_tracking(2, &mut dropped));
+    /// assert_eq!(dropped, 1);
     /// ```
     ///
-    pub fn retain_mut<F>(&mut self, mut f: F)
-    where
-        F: FnMut(&mut T) -> bool,
-    {
-        let mut i = 0;
-        let mut len = self.len;
+    pub fn push_tracking(&mut self, value: T, overflow_count: &mut usize) -> bool {
+        if self.push(value).is_err() {
+            *overflow_count += 1;
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Pushes an element, if the vec is full the oldest (first) element is removed to make room and returned.
+    /// When full this shifts every element down by one, so it is O(CAP) per push.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 2> = vec_arr![1, 2];
+    /// assert_eq!(vec.push_overwrite(3), Some(1));
+    /// assert_eq!(vec, vec![2, 3]);
+    /// ```
+    ///
+    pub fn push_overwrite(&mut self, value: T) -> Option<T> {
+        if !self.is_full() {
+            // infallible
+            let _ = self.push(value);
+            None
+        } else if self.is_empty() {
+            Some(value)
+        } else {
+            self.as_mut_slice().rotate_left(1);
+            let last = self.len - 1;
+            Some(::std::mem::replace(&mut self[last], value))
+        }
+    }
+
+    /// Pushes an element if it is not greater than `max`, otherwise the value is given back in the error.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    /// use vector_array::error::PushError;
+    ///
+    /// let mut vec: VecArray<_, 10> = VecArray::new();
+    /// assert_eq!(vec.push_bounded(5, &10), Ok(()));
+    /// assert_eq!(vec.push_bounded(11, &10), Err(PushError::OutOfBounds(11)));
+    /// ```
+    ///
+    pub fn push_bounded(&mut self, value: T, max: &T) -> Result<(), PushError<T>>
+    where
+        T: PartialOrd,
+    {
+        if value > *max {
+            Err(PushError::OutOfBounds(value))
+        } else {
+            self.push_or(value, PushError::Full)
+        }
+    }
+
+    /// Pushes every item of the iterator, stops at the first item that does not fit.
+    /// The items pushed before the overflow stay in the vec, the error holds how many were added and the item that did not fit.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 3> = vec_arr![1];
+    /// assert_eq!(vec.try_extend([2, 3, 4]), Err((2, 4)));
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), (usize, T)> {
+        for (added, x) in iter.into_iter().enumerate() {
+            if self.is_full() {
+                return Err((added, x));
+            }
+            // infallible
+            let _ = self.push(x);
+        }
+        Ok(())
+    }
+
+    /// Clones and pushes all elements of the slice, nothing is pushed if they don't all fit.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1, 2];
+    /// vec.extend_from_slice(&[3, 4]).unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3, 4]);
+    /// ```
+    ///
+    pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), ArrTooSmall>
+    where
+        T: Clone,
+    {
+        self.check_capacity(other.len())?;
+        for x in other {
+            // infallible
+            let _ = self.push(x.clone());
+        }
+        Ok(())
+    }
+
+    /// Moves all elements of `other` to the end of the vec, leaving `other` empty.
+    /// Both vecs are left untouched if the elements don't fit.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut a: VecArray<_, 10> = vec_arr![1, 2];
+    /// let mut b: VecArray<_, 5> = vec_arr![3, 4];
+    /// a.append(&mut b).unwrap();
+    /// assert_eq!(a, vec![1, 2, 3, 4]);
+    /// assert!(b.is_empty());
+    /// ```
+    ///
+    pub fn append<const CAP2: usize>(
+        &mut self,
+        other: &mut VecArray<T, CAP2>,
+    ) -> Result<(), ArrTooSmall> {
+        self.check_capacity(other.len)?;
+        unsafe {
+            ::std::ptr::copy_nonoverlapping(
+                other.as_ptr(),
+                self.as_mut_ptr().add(self.len),
+                other.len,
+            );
+        }
+        self.len += other.len;
+        // the elements are now owned by self
+        other.len = 0;
+        Ok(())
+    }
+
+    /// Pushes the element only if it isn't already in the vec, returns whether it was pushed.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2];
+    /// assert_eq!(vec.push_unique(3), Ok(true));
+    /// assert_eq!(vec.push_unique(1), Ok(false));
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn push_unique(&mut self, value: T) -> Result<bool, ArrTooSmall>
+    where
+        T: PartialEq,
+    {
+        if self.as_slice().contains(&value) {
+            Ok(false)
+        } else {
+            self.push(value).map(|()| true)
+        }
+    }
+
+    /// Pushes the element only if `cond` returns true for the current vec, returns whether it was pushed.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2];
+    /// assert_eq!(vec.push_if(3, |v| v.len() < 3), Ok(true));
+    /// assert_eq!(vec.push_if(4, |v| v.len() < 3), Ok(false));
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn push_if<F: FnOnce(&Self) -> bool>(
+        &mut self,
+        value: T,
+        cond: F,
+    ) -> Result<bool, ArrTooSmall> {
+        if cond(self) {
+            self.push(value).map(|()| true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Removes the last element
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::vec::VecArray;
+    ///
+    /// let mut vec: VecArray<_, 10> = VecArray::new();
+    /// vec.push(9).unwrap();
+    /// assert_eq!(vec.pop(), Some(9));
+    /// ```
+    ///
+    /// # Safety
+    /// Returns memory which will realistically wont be used anymore
+    ///
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some(unsafe { self.arr[self.len].assume_init_read() })
+        }
+    }
+
+    /// Removes the last `n` elements (or all if `n > len`) and returns them in their original order.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// assert_eq!(vec.pop_n(3), vec![2, 3, 4]);
+    /// assert_eq!(vec, vec![1]);
+    /// ```
+    ///
+    pub fn pop_n(&mut self, n: usize) -> VecArray<T, CAP> {
+        let n = n.min(self.len);
+        let start = self.len - n;
+        let mut out = Self::new();
+        // the moved elements are no longer owned by self
+        self.len = start;
+        unsafe {
+            ::std::ptr::copy_nonoverlapping(self.arr.as_ptr().add(start), out.arr.as_mut_ptr(), n);
+        }
+        out.len = n;
+        out
+    }
+
+    /// Splits the vec in two at `at`, returns the elements `[at, len)` and keeps `[0, at)`.
+    ///
+    /// # Panics
+    /// If at is greater than length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let tail = vec.split_off(1);
+    /// assert_eq!(vec, vec![1]);
+    /// assert_eq!(tail, vec![2, 3]);
+    /// ```
+    ///
+    pub fn split_off(&mut self, at: usize) -> VecArray<T, CAP> {
+        if at > self.len {
+            panic!(
+                "split_off index (is {at}) should be <= len (is {})",
+                self.len
+            );
+        }
+        self.pop_n(self.len - at)
+    }
+
+    /// Removes an element.
+    ///
+    /// # Panics
+    /// If index is greater than or equal to length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::vec::VecArray;
+    /// let mut vec: VecArray<_, 10> = VecArray::new();
+    /// vec.push(9).unwrap();
+    /// vec.remove(0);
+    /// assert!(vec.is_empty());
+    /// ```
+    ///
+    /// # Safety
+    /// Copied from Vec source code
+    ///
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        if index >= len {
+            panic!("Removal index (is {index}) should be < len (is {len})");
+        }
+
+        // infallible
+        let ret;
+        unsafe {
+            // the place we are taking from.
+            let ptr = self.as_mut_ptr().add(index);
+            // copy it out, unsafely having a copy of the value on
+            // the stack and in the vector at the same time.
+            ret = ::std::ptr::read(ptr);
+
+            // Shift everything down to fill in that spot.
+            ::std::ptr::copy(ptr.add(1), ptr, len - index - 1);
+        }
+        self.len -= 1;
+        ret
+    }
+
+    /// Inserts an element at position index within the vector, shifting all elements after it to the right.
+    ///
+    /// # Panics
+    /// If index is greater than length or new length is greater than CAP
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// vec.insert(1, 4);
+    /// assert_eq!(vec, vec![1, 4, 2, 3]);
+    /// vec.insert(2, 5);
+    /// assert_eq!(vec, vec![1, 4, 5, 2, 3]);
+    /// vec.insert(5, 6);
+    /// assert_eq!(vec, vec![1, 4, 5, 2, 3, 6]);
+    /// ```
+    ///
+    pub fn insert(&mut self, index: usize, element: T) {
+        if self.try_insert(index, element).is_err() {
+            panic!("Array too small")
+        }
+    }
+
+    /// Inserts an element at position index within the vector, shifting all elements after it to the right.
+    /// Returns an error and leaves the vec unchanged if it is full.
+    ///
+    /// # Panics
+    /// If index is greater than length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1, 2, 3];
+    /// vec.try_insert(3, 4).unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3, 4]);
+    /// assert!(vec.try_insert(0, 0).is_err());
+    /// ```
+    ///
+    /// # Safety
+    /// Copied from Vec source code
+    ///
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), ArrTooSmall> {
+        if index > self.len {
+            panic!(
+                "insertion index (is {index}) should be <= len (is {})",
+                self.len
+            );
+        }
+
+        if self.len >= CAP {
+            return Err(ArrTooSmall);
+        }
+
+        unsafe {
+            let ptr = self.as_mut_ptr().add(index);
+            ::std::ptr::copy(ptr, ptr.add(1), self.len - index);
+            ::std::ptr::write(ptr, element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes an element from the vector and returns it.
+    ///
+    /// The removed element is replaced by the last element of the vector.
+    ///
+    /// This does not preserve ordering, but is *O*(1).
+    /// If you need to preserve the element order, use `remove` instead.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut v: VecArray<_, 10> = vec_arr!["foo", "bar", "baz", "qux"];
+    ///
+    /// assert_eq!(v.swap_remove(1), "bar");
+    /// assert_eq!(v, &["foo", "qux", "baz"][..]);
+    ///
+    /// assert_eq!(v.swap_remove(0), "foo");
+    /// assert_eq!(v, &["baz", "qux"][..]);
+    /// ```
+    ///
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.len {
+            panic!(
+                "swap_remove index (is {index}) should be < len (is {})",
+                self.len
+            );
+        }
+
+        self.len -= 1;
+
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let ret = ::std::ptr::read(ptr.add(index));
+            ::std::ptr::copy(ptr.add(self.len), ptr.add(index), 1);
+            ret
+        }
+    }
+
+    /// Swaps two elements in the vec.
+    ///
+    /// # Panics
+    /// Panics if one of the indexes are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    /// vec.swap(2, 3);
+    /// assert_eq!(vec, vec![0, 1, 3, 2]);
+    /// ```
+    ///
+    pub fn swap(&mut self, index1: usize, index2: usize) {
+        if index1 >= self.len || index2 >= self.len {
+            panic!("Index out of bounds");
+        }
+        if index1 == index2 {
+            return;
+        }
+        self.as_mut_slice().swap(index1, index2);
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// vec.retain(|&x| x % 2 == 0);
+    /// assert_eq!(vec, vec![2, 4]);
+    /// ```
+    ///
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| f(x));
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to it.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns `false`.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// vec.retain_mut(|x| if *x <= 3 {
+    ///     *x += 1;
+    ///     true
+    /// } else {
+    ///     false
+    /// });
+    /// assert_eq!(vec, vec![2, 3, 4]);
+    /// ```
+    ///
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut i = 0;
+        let mut len = self.len;
         while i < len {
-            if !f(&mut self.arr[i]) {
+            if !f(&mut self.as_mut_slice()[i]) {
                 self.remove(i);
                 len -= 1;
             } else {
-                i += 1;
+                i += 1;
+            }
+        }
+        self.len = len;
+    }
+
+    /// Retains only the elements for which `validate` returns Ok, returns the errors of the removed elements in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// let errors = vec.retain_valid(|&x| if x % 2 == 0 { Ok(()) } else { Err(x) });
+    /// assert_eq!(vec, vec![2, 4]);
+    /// assert_eq!(errors, vec![1, 3]);
+    /// ```
+    ///
+    pub fn retain_valid<F, E>(&mut self, mut validate: F) -> Vec<E>
+    where
+        F: FnMut(&T) -> Result<(), E>,
+    {
+        let mut errors = Vec::new();
+        self.retain(|x| match validate(x) {
+            Ok(()) => true,
+            Err(e) => {
+                errors.push(e);
+                false
+            }
+        });
+        errors
+    }
+
+    /// Removes all elements for which `pred` returns true and returns them in a Vec,
+    /// the order of both the removed and the retained elements is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// assert_eq!(vec.split_off_matching(|x| *x > 2), vec![3, 4]);
+    /// assert_eq!(vec, vec![1, 2]);
+    /// ```
+    ///
+    pub fn split_off_matching<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut matching = Vec::new();
+        // if pred panics the elements that weren't visited yet are forgotten
+        let len = ::std::mem::replace(&mut self.len, 0);
+        let mut write = 0;
+        for read in 0..len {
+            let x = unsafe { self.arr[read].assume_init_read() };
+            if pred(&x) {
+                matching.push(x);
+            } else {
+                self.arr[write].write(x);
+                write += 1;
+                self.len = write;
+            }
+        }
+        matching
+    }
+
+    /// Moves all but the first of consecutive equal elements to the end of the vec
+    /// and returns the deduplicated prefix. Nothing is dropped and the length is unchanged.
+    ///
+    /// If the vec is sorted, the returned prefix contains no duplicates.
+    ///
+    /// # Examples
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 1, 2, 3, 3];
+    /// assert_eq!(vec.partition_dedup(), &[1, 2, 3]);
+    /// assert_eq!(vec.len(), 5);
+    /// ```
+    ///
+    pub fn partition_dedup(&mut self) -> &mut [T]
+    where
+        T: PartialEq,
+    {
+        let slice = self.as_mut_slice();
+        if slice.is_empty() {
+            return slice;
+        }
+
+        let mut write = 1;
+        for read in 1..slice.len() {
+            if slice[read] != slice[write - 1] {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+        &mut slice[..write]
+    }
+
+    /// Removes consecutive repeated elements, if the vec is sorted this removes all duplicates.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 1, 2, 1, 1];
+    /// vec.dedup();
+    /// assert_eq!(vec, vec![1, 2, 1]);
+    /// ```
+    ///
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements that resolve to the same key.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![10, 20, 21, 30, 20];
+    /// vec.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(vec, vec![10, 20, 30, 20]);
+    /// ```
+    ///
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns true.
+    ///
+    /// `same_bucket` is passed the element in question and the last element that was kept, in that order.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr!["a", "A", "b", "B", "b"];
+    /// vec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(vec, vec!["a", "b"]);
+    /// ```
+    ///
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let slice = self.as_mut_slice();
+        if slice.is_empty() {
+            return;
+        }
+
+        // the duplicates are moved behind write and dropped by truncate
+        let mut write = 1;
+        for read in 1..slice.len() {
+            let (kept, rest) = slice.split_at_mut(read);
+            if !same_bucket(&mut rest[0], &mut kept[write - 1]) {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+        self.truncate(write);
+    }
+
+    /// Rotates the vec in-place such that the first `mid` elements move to the end.
+    ///
+    /// # Panics
+    /// If `mid` is greater than length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// vec.rotate_left(2);
+    /// assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+    /// ```
+    ///
+    pub fn rotate_left(&mut self, mid: usize) {
+        let len = self.len;
+        if mid > len {
+            panic!("Rotation mid (is {mid}) should be <= len (is {len})");
+        }
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    /// Rotates the vec in-place such that the last `k` elements move to the front.
+    ///
+    /// # Panics
+    /// If `k` is greater than length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// vec.rotate_right(2);
+    /// assert_eq!(vec, vec![4, 5, 1, 2, 3]);
+    /// ```
+    ///
+    pub fn rotate_right(&mut self, k: usize) {
+        let len = self.len;
+        if k > len {
+            panic!("Rotation k (is {k}) should be <= len (is {len})");
+        }
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    /// Rotates the vec in-place such that the first `mid` elements move to the end.
+    ///
+    /// Same result as `as_mut_slice().rotate_left(mid)`, but the shorter side is moved through a
+    /// scratch `[MaybeUninit<T>; CAP]` on the stack, so every element is copied at most twice.
+    /// Depending on CAP and `mid` this can be faster, at the cost of the extra stack space.
+    /// Compare both on your target with `cargo bench --bench rotate`.
+    ///
+    /// # Panics
+    /// If `mid` is greater than length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// vec.rotate_left_cached(2);
+    /// assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+    /// ```
+    ///
+    pub fn rotate_left_cached(&mut self, mid: usize) {
+        let len = self.len;
+        if mid > len {
+            panic!("Rotation mid (is {mid}) should be <= len (is {len})");
+        }
+        if mid == 0 || mid == len {
+            return;
+        }
+
+        let mut scratch: [MaybeUninit<T>; CAP] = unsafe { MaybeUninit::uninit().assume_init() };
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let tmp = scratch.as_mut_ptr() as *mut T;
+            if mid <= len - mid {
+                ::std::ptr::copy_nonoverlapping(ptr, tmp, mid);
+                ::std::ptr::copy(ptr.add(mid), ptr, len - mid);
+                ::std::ptr::copy_nonoverlapping(tmp, ptr.add(len - mid), mid);
+            } else {
+                ::std::ptr::copy_nonoverlapping(ptr.add(mid), tmp, len - mid);
+                ::std::ptr::copy(ptr, ptr.add(len - mid), mid);
+                ::std::ptr::copy_nonoverlapping(tmp, ptr, len - mid);
+            }
+        }
+    }
+
+    /// Pushes every item of `items` in order.
+    ///
+    /// On the first item that doesn't fit, stops and returns its position in `items` together with the item.
+    /// Everything before it stays pushed.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 3> = vec_arr![1];
+    /// assert_eq!(vec.try_push_many([2, 3, 4, 5]), Err((2, 4)));
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn try_push_many(&mut self, items: impl IntoIterator<Item = T>) -> Result<(), (usize, T)> {
+        for (i, item) in items.into_iter().enumerate() {
+            if self.is_full() {
+                return Err((i, item));
+            }
+            // infallible
+            let _ = self.push(item);
+        }
+        Ok(())
+    }
+
+    /// Moves all elements for which `pred` returns `true` to the front of the vec, keeping the
+    /// relative order of both the matching and the non-matching elements. Nothing is removed.
+    ///
+    /// Returns the number of matching elements.
+    ///
+    /// Runs in linear time, the non-matching elements are moved through a scratch
+    /// `[MaybeUninit<T>; CAP]` on the stack.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5, 6];
+    /// assert_eq!(vec.move_to_front(|&x| x % 2 == 0), 3);
+    /// assert_eq!(vec, vec![2, 4, 6, 1, 3, 5]);
+    /// ```
+    ///
+    pub fn move_to_front<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // pred is called on every element before anything is moved, so a panic leaves the vec intact
+        let mut matches = [false; CAP];
+        for (m, x) in matches.iter_mut().zip(self.as_slice()) {
+            *m = pred(x);
+        }
+
+        let mut scratch: [MaybeUninit<T>; CAP] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut count = 0;
+        let mut rest = 0;
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            let tmp = scratch.as_mut_ptr() as *mut T;
+            for (i, &m) in matches[..self.len].iter().enumerate() {
+                if m {
+                    ::std::ptr::copy(ptr.add(i), ptr.add(count), 1);
+                    count += 1;
+                } else {
+                    ::std::ptr::copy_nonoverlapping(ptr.add(i), tmp.add(rest), 1);
+                    rest += 1;
+                }
+            }
+            ::std::ptr::copy_nonoverlapping(tmp, ptr.add(count), rest);
+        }
+        count
+    }
+
+    /// Calls `f` on consecutive chunks of `size` elements. The last chunk is shorter if the length
+    /// isn't a multiple of `size`, and `f` isn't called at all on an empty vec.
+    ///
+    /// # Panics
+    /// If size is 0
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// let mut sums = Vec::new();
+    /// vec.for_each_chunk(2, |chunk| sums.push(chunk.iter().sum::<i32>()));
+    /// assert_eq!(sums, vec![3, 7, 5]);
+    /// ```
+    ///
+    pub fn for_each_chunk<F>(&self, size: usize, f: F)
+    where
+        F: FnMut(&[T]),
+    {
+        if size == 0 {
+            panic!("Chunk size should be > 0");
+        }
+        self.as_slice().chunks(size).for_each(f);
+    }
+
+    /// Clones `value` into every free slot, afterwards the vec is full.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1, 2];
+    /// vec.fill_to_capacity(0);
+    /// assert_eq!(vec, vec![1, 2, 0, 0]);
+    /// ```
+    ///
+    pub fn fill_to_capacity(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        while self.len < CAP {
+            // infallible
+            let _ = self.push(value.clone());
+        }
+    }
+
+    /// Overwrites every element with a clone of `value`, the length stays the same.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1, 2];
+    /// vec.fill(0);
+    /// assert_eq!(vec, vec![0, 0]);
+    /// ```
+    ///
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Overwrites every element with the values returned by `f`, the length stays the same.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![0, 0, 0];
+    /// let mut next = 0;
+    /// vec.fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.as_mut_slice().fill_with(f);
+    }
+
+    /// Resizes the vec to `new_len`, pushes clones of `value` when growing and drops the tail when shrinking.
+    ///
+    /// # Panics
+    /// If new_len is greater than CAP
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1, 2];
+    /// vec.resize(4, 0);
+    /// assert_eq!(vec, vec![1, 2, 0, 0]);
+    /// vec.resize(1, 0);
+    /// assert_eq!(vec, vec![1]);
+    /// ```
+    ///
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if self.try_resize(new_len, value).is_err() {
+            panic!("new_len (is {new_len}) should be <= capacity (is {CAP})");
+        }
+    }
+
+    /// Resizes the vec to `new_len`, pushes the values returned by `f` when growing and drops the tail when shrinking.
+    ///
+    /// # Panics
+    /// If new_len is greater than CAP
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1];
+    /// let mut next = 1;
+    /// vec.resize_with(3, || {
+    ///     next *= 2;
+    ///     next
+    /// });
+    /// assert_eq!(vec, vec![1, 2, 4]);
+    /// ```
+    ///
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        if new_len > CAP {
+            panic!("new_len (is {new_len}) should be <= capacity (is {CAP})");
+        }
+
+        if new_len <= self.len {
+            self.truncate(new_len);
+        } else {
+            while self.len < new_len {
+                // infallible
+                let _ = self.push(f());
+            }
+        }
+    }
+
+    /// Same as resize but returns an error instead of panicking, the vec is unchanged on error.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 4> = vec_arr![1, 2];
+    /// assert!(vec.try_resize(5, 0).is_err());
+    /// assert_eq!(vec, vec![1, 2]);
+    /// ```
+    ///
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), ArrTooSmall>
+    where
+        T: Clone,
+    {
+        if new_len > CAP {
+            return Err(ArrTooSmall);
+        }
+        self.resize_with(new_len, || value.clone());
+        Ok(())
+    }
+
+    /// Returns the number of positions where the elements of both vecs differ,
+    /// or None if the lengths are not equal.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let a: VecArray<_, 10> = vec_arr![1, 0, 1, 1];
+    /// let b: VecArray<_, 10> = vec_arr![1, 1, 1, 0];
+    /// assert_eq!(a.hamming_distance(&b), Some(2));
+    /// ```
+    ///
+    pub fn hamming_distance(&self, other: &Self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        if self.len != other.len {
+            None
+        } else {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .filter(|(a, b)| a != b)
+                    .count(),
+            )
+        }
+    }
+
+    /// Rotates the vec so that the first element equal to `value` ends up at index 0.
+    ///
+    /// Returns false and leaves the vec unchanged if no element is equal to `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![3, 4, 1, 2];
+    /// assert!(vec.rotate_to_value(&1));
+    /// assert_eq!(vec, vec![1, 2, 3, 4]);
+    /// ```
+    ///
+    pub fn rotate_to_value(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.iter().position(|x| x == value) {
+            Some(index) => {
+                self.as_mut_slice().rotate_left(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces every element with `f(&old)`, the old value is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// vec.map_in_place(|x| x * 10);
+    /// assert_eq!(vec, vec![10, 20, 30]);
+    /// ```
+    ///
+    pub fn map_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> T,
+    {
+        for x in self.iter_mut() {
+            *x = f(x);
+        }
+    }
+
+    /// Replaces every element equal to `from` with a clone of `to` and returns how many were replaced.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 0, 2, 0];
+    /// assert_eq!(vec.replace_all(&0, &9), 2);
+    /// assert_eq!(vec, vec![1, 9, 2, 9]);
+    /// ```
+    ///
+    pub fn replace_all(&mut self, from: &T, to: &T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut count = 0;
+        for x in self.iter_mut() {
+            if x == from {
+                *x = to.clone();
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Returns the last `n` elements without removing them, or None if there are fewer than `n`.
+    ///
+    /// Handy when using the vec as a stack and the top operands need to be inspected before popping.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.peek_n(2), Some(&[2, 3][..]));
+    /// assert_eq!(vec.peek_n(4), None);
+    /// ```
+    ///
+    pub fn peek_n(&self, n: usize) -> Option<&[T]> {
+        if n > self.len {
+            None
+        } else {
+            Some(&self.as_slice()[self.len - n..])
+        }
+    }
+
+    /// Returns the dot product of both vecs, or None if the lengths are not equal.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let a: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let b: VecArray<_, 10> = vec_arr![4, 5, 6];
+    /// assert_eq!(a.dot(&b), Some(32));
+    /// ```
+    ///
+    pub fn dot(&self, other: &Self) -> Option<T>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Copy + Default,
+    {
+        if self.len != other.len {
+            None
+        } else {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .fold(T::default(), |acc, (&a, &b)| acc + a * b),
+            )
+        }
+    }
+
+    /// Multiplies every element by `factor`.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// vec.scale(2);
+    /// assert_eq!(vec, vec![2, 4, 6]);
+    /// ```
+    ///
+    pub fn scale(&mut self, factor: T)
+    where
+        T: MulAssign + Copy,
+    {
+        for x in self.iter_mut() {
+            *x *= factor;
+        }
+    }
+
+    /// Returns the arithmetic mean of the elements, or None if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![2.0, 4.0, 6.0];
+    /// assert_eq!(vec.mean(), Some(4.0));
+    /// ```
+    ///
+    pub fn mean(&self) -> Option<f64>
+    where
+        T: Into<f64> + Copy,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.iter().map(|&x| x.into()).sum::<f64>() / self.len as f64)
+        }
+    }
+
+    /// Returns the population variance of the elements, or None if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![2.0, 4.0, 6.0];
+    /// assert_eq!(vec.variance(), Some(8.0 / 3.0));
+    /// ```
+    ///
+    pub fn variance(&self) -> Option<f64>
+    where
+        T: Into<f64> + Copy,
+    {
+        let mean = self.mean()?;
+        Some(self.iter().map(|&x| (x.into() - mean).powi(2)).sum::<f64>() / self.len as f64)
+    }
+
+    /// Moves every element `by` positions to the right and fills the gap at the front with clones of `fill`.
+    ///
+    /// Returns an error and leaves the vec unchanged if the new length would be greater than CAP.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// vec.shift_right(2, 0).unwrap();
+    /// assert_eq!(vec, vec![0, 0, 1, 2, 3]);
+    /// ```
+    ///
+    pub fn shift_right(&mut self, by: usize, fill: T) -> Result<(), ArrTooSmall>
+    where
+        T: Clone,
+    {
+        self.check_capacity(by)?;
+        for _ in 0..by {
+            // infallible
+            let _ = self.push(fill.clone());
+        }
+        self.as_mut_slice().rotate_right(by);
+        Ok(())
+    }
+
+    /// Returns the index range of the longest strictly increasing run of consecutive elements.
+    /// The first one is returned if there are several of the same length, and `0..0` if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 1, 2, 3, 4, 1];
+    /// assert_eq!(vec.longest_increasing_run(), 2..6);
+    /// ```
+    ///
+    pub fn longest_increasing_run(&self) -> Range<usize>
+    where
+        T: PartialOrd,
+    {
+        if self.is_empty() {
+            return 0..0;
+        }
+
+        let slice = self.as_slice();
+        let mut best = 0..1;
+        let mut start = 0;
+        for i in 1..slice.len() {
+            if slice[i - 1].partial_cmp(&slice[i]) != Some(Ordering::Less) {
+                start = i;
+            }
+            if i + 1 - start > best.len() {
+                best = start..i + 1;
+            }
+        }
+        best
+    }
+
+    /// Splits the vec around the first element for which `pred` returns `true`, the element itself is
+    /// in neither part. Returns None if no element matches.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 0, 3];
+    /// assert_eq!(vec.split_once(|&x| x == 0), Some((&[1, 2][..], &[3][..])));
+    /// ```
+    ///
+    pub fn split_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let slice = self.as_slice();
+        let index = slice.iter().position(pred)?;
+        Some((&slice[..index], &slice[index + 1..]))
+    }
+
+    /// Rotates the vec left by `mid` and returns clones of the elements which wrapped around to the back.
+    ///
+    /// # Panics
+    /// If `mid` is greater than length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// assert_eq!(vec.rotate_left_collecting(2), vec![1, 2]);
+    /// assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+    /// ```
+    ///
+    pub fn rotate_left_collecting(&mut self, mid: usize) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let len = self.len;
+        if mid > len {
+            panic!("Rotation mid (is {mid}) should be <= len (is {len})");
+        }
+        self.as_mut_slice().rotate_left(mid);
+        self.as_slice()[len - mid..].to_vec()
+    }
+
+    /// Keeps only the elements for which `keep` returns `true` in a single pass and returns the new length.
+    ///
+    /// Like ::retain, but every kept element is moved at most once, the order is preserved.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// assert_eq!(vec.gc(|&x| x != 2), 4);
+    /// assert_eq!(vec, vec![1, 3, 4, 5]);
+    /// ```
+    ///
+    pub fn gc<F>(&mut self, mut keep: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let slice = self.as_mut_slice();
+        let mut write = 0;
+        for read in 0..slice.len() {
+            if keep(&slice[read]) {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+        while self.len > write {
+            self.pop();
+        }
+        write
+    }
+
+    /// Returns true if the elements read the same forwards and backwards, an empty vec is a palindrome.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 1];
+    /// assert!(vec.is_palindrome());
+    /// ```
+    ///
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let slice = self.as_slice();
+        let half = slice.len() / 2;
+        slice[..half]
+            .iter()
+            .eq(slice[slice.len() - half..].iter().rev())
+    }
+
+    /// Rotates the vec left until the first element satisfies `pred`.
+    ///
+    /// Returns false and leaves the vec unchanged if no element matches.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<u8, 10> = vec_arr![0x12, 0x7e, 0x01, 0x02];
+    /// assert!(vec.align_front(|&b| b == 0x7e));
+    /// assert_eq!(vec, vec![0x7e, 0x01, 0x02, 0x12]);
+    /// ```
+    ///
+    pub fn align_front<F>(&mut self, pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match self.iter().position(pred) {
+            Some(index) => {
+                self.as_mut_slice().rotate_left(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Appends clones of all elements to `dst`.
+    ///
+    /// Returns an error and leaves `dst` unchanged if it doesn't have enough room.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let src: VecArray<_, 10> = vec_arr![2, 3];
+    /// let mut dst: VecArray<_, 5> = vec_arr![1];
+    /// src.copy_to(&mut dst).unwrap();
+    /// assert_eq!(dst, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn copy_to<const CAP2: usize>(&self, dst: &mut VecArray<T, CAP2>) -> Result<(), ArrTooSmall>
+    where
+        T: Clone,
+    {
+        dst.check_capacity(self.len)?;
+        for x in self.iter() {
+            // infallible
+            let _ = dst.push(x.clone());
+        }
+        Ok(())
+    }
+
+    /// Moves the element at `from` to `to`, shifting the elements in between.
+    ///
+    /// # Panics
+    /// If `from` or `to` is greater than or equal to length
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// vec.move_element(0, 2);
+    /// assert_eq!(vec, vec![2, 3, 1, 4]);
+    /// ```
+    ///
+    pub fn move_element(&mut self, from: usize, to: usize) {
+        let len = self.len;
+        if from >= len || to >= len {
+            panic!("Move indices (are {from} and {to}) should be < len (is {len})");
+        }
+
+        let slice = self.as_mut_slice();
+        if from < to {
+            slice[from..=to].rotate_left(1);
+        } else {
+            slice[to..=from].rotate_right(1);
+        }
+    }
+
+    /// Returns the number of distinct elements. Compares every pair, so this is *O*(n²).
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 1, 2, 3, 3];
+    /// assert_eq!(vec.count_distinct(), 3);
+    /// ```
+    ///
+    pub fn count_distinct(&self) -> usize
+    where
+        T: PartialEq,
+    {
+        let slice = self.as_slice();
+        slice
+            .iter()
+            .enumerate()
+            .filter(|&(i, x)| !slice[..i].contains(x))
+            .count()
+    }
+
+    /// Returns every distinct element together with how often it occurs, in order of first occurrence.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr!['a', 'b', 'a'];
+    /// assert_eq!(vec.frequencies(), vec![('a', 2), ('b', 1)]);
+    /// ```
+    ///
+    pub fn frequencies(&self) -> Vec<(T, usize)>
+    where
+        T: PartialEq + Clone,
+    {
+        let mut frequencies: Vec<(T, usize)> = Vec::new();
+        for x in self.iter() {
+            match frequencies.iter_mut().find(|(y, _)| y == x) {
+                Some((_, count)) => *count += 1,
+                None => frequencies.push((x.clone(), 1)),
+            }
+        }
+        frequencies
+    }
+
+    /// Merges two sorted vecs into one sorted vec with capacity OUT, equal elements from `self` come first.
+    ///
+    /// Returns an error if the combined length is greater than OUT.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let a: VecArray<_, 10> = vec_arr![1, 3, 5];
+    /// let b: VecArray<_, 10> = vec_arr![2, 4];
+    /// let merged: VecArray<_, 10> = a.merge_sorted(b).unwrap();
+    /// assert_eq!(merged, vec![1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    pub fn merge_sorted<const CAP2: usize, const OUT: usize>(
+        self,
+        other: VecArray<T, CAP2>,
+    ) -> Result<VecArray<T, OUT>, ArrTooSmall>
+    where
+        T: Ord,
+    {
+        let mut merged = VecArray::new_no_default();
+        merged.check_capacity(self.len + other.len)?;
+
+        let mut a = self.into_iter().peekable();
+        let mut b = other.into_iter().peekable();
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if y < x => b.next(),
+                (Some(_), _) => a.next(),
+                (None, _) => b.next(),
+            };
+            match next {
+                Some(x) => {
+                    // infallible
+                    let _ = merged.push(x);
+                }
+                None => return Ok(merged),
             }
         }
-        self.len = len;
     }
 
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.len {
+    /// Returns for every index the maximum of all elements up to and including it.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 3, 2, 5, 4];
+    /// assert_eq!(vec.running_max(), vec![1, 3, 3, 5, 5]);
+    /// ```
+    ///
+    pub fn running_max(&self) -> VecArray<T, CAP>
+    where
+        T: PartialOrd + Copy,
+    {
+        let mut ret = VecArray::new();
+        for &x in self.iter() {
+            let max = match ret.last() {
+                Some(&max) if max > x => max,
+                _ => x,
+            };
+            // infallible
+            let _ = ret.push(max);
+        }
+        ret
+    }
+
+    /// Returns for every index the product of all elements up to and including it.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![2, 3, 4];
+    /// assert_eq!(vec.prefix_product(), vec![2, 6, 24]);
+    /// ```
+    ///
+    pub fn prefix_product(&self) -> VecArray<T, CAP>
+    where
+        T: Mul<Output = T> + Copy,
+    {
+        let mut ret = VecArray::new();
+        for &x in self.iter() {
+            let product = match ret.last() {
+                Some(&product) => product * x,
+                None => x,
+            };
+            // infallible
+            let _ = ret.push(product);
+        }
+        ret
+    }
+
+    /// Writes `values[k]` to position `indices[k]` for every k.
+    /// Nothing is written if the lengths differ or any index is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![0, 0, 0];
+    /// vec.scatter(&[2, 0], &[5, 7]).unwrap();
+    /// assert_eq!(vec, vec![7, 0, 5]);
+    /// assert!(vec.scatter(&[3], &[1]).is_err());
+    /// ```
+    ///
+    #[allow(clippy::result_unit_err)]
+    pub fn scatter(&mut self, indices: &[usize], values: &[T]) -> Result<(), ()>
+    where
+        T: Clone,
+    {
+        if indices.len() != values.len() || indices.iter().any(|&i| i >= self.len) {
+            return Err(());
+        }
+        for (&i, x) in indices.iter().zip(values) {
+            self.as_mut_slice()[i] = x.clone();
+        }
+        Ok(())
+    }
+
+    /// Returns clones of the elements at the given indices, None if any index is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr!['a', 'b', 'c'];
+    /// assert_eq!(vec.gather(&[2, 2, 0]), Some(vec!['c', 'c', 'a']));
+    /// assert_eq!(vec.gather(&[3]), None);
+    /// ```
+    ///
+    pub fn gather(&self, indices: &[usize]) -> Option<Vec<T>>
+    where
+        T: Clone,
+    {
+        indices.iter().map(|&i| self.get(i).cloned()).collect()
+    }
+
+    /// Returns true if all elements are equal to the first one, also true if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 1, 1];
+    /// assert!(vec.all_equal());
+    /// ```
+    ///
+    pub fn all_equal(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.first() {
+            Some(first) => self.as_slice().iter().all(|x| x == first),
+            None => true,
+        }
+    }
+
+    /// Combines the elements of both vecs pairwise with `f`, up to the length of the shorter one.
+    ///
+    /// # Panics
+    /// If the shorter length is greater than OUT
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let a: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let b: VecArray<_, 5> = vec_arr!["a", "b"];
+    /// let zipped: VecArray<_, 10> = a.zip_map(&b, |x, y| format!("{y}{x}"));
+    /// assert_eq!(zipped, vec!["a1".to_string(), "b2".to_string()]);
+    /// ```
+    ///
+    pub fn zip_map<U, R, const CAP2: usize, const OUT: usize, F>(
+        &self,
+        other: &VecArray<U, CAP2>,
+        mut f: F,
+    ) -> VecArray<R, OUT>
+    where
+        F: FnMut(&T, &U) -> R,
+    {
+        let len = self.len.min(other.len);
+        if len > OUT {
+            panic!("Length (is {len}) should be <= output capacity (is {OUT})");
+        }
+
+        let mut out = VecArray::new();
+        for (x, y) in self.as_slice().iter().zip(other.as_slice()) {
+            // infallible
+            let _ = out.push(f(x, y));
+        }
+        out
+    }
+
+    /// Bitwise ANDs all elements together, None if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<u8, 10> = vec_arr![0b110, 0b011];
+    /// assert_eq!(vec.fold_and(), Some(0b010));
+    /// ```
+    ///
+    pub fn fold_and(&self) -> Option<T>
+    where
+        T: BitAnd<Output = T> + Copy,
+    {
+        self.as_slice().iter().copied().reduce(|acc, x| acc & x)
+    }
+
+    /// Bitwise ORs all elements together, None if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<u8, 10> = vec_arr![0b100, 0b001];
+    /// assert_eq!(vec.fold_or(), Some(0b101));
+    /// ```
+    ///
+    pub fn fold_or(&self) -> Option<T>
+    where
+        T: BitOr<Output = T> + Copy,
+    {
+        self.as_slice().iter().copied().reduce(|acc, x| acc | x)
+    }
+
+    /// Bitwise XORs all elements together, None if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<u8, 10> = vec_arr![0b110, 0b011];
+    /// assert_eq!(vec.fold_xor(), Some(0b101));
+    /// ```
+    ///
+    pub fn fold_xor(&self) -> Option<T>
+    where
+        T: BitXor<Output = T> + Copy,
+    {
+        self.as_slice().iter().copied().reduce(|acc, x| acc ^ x)
+    }
+
+    /// Formats all elements separated by `sep` into a byte vec, returns an error if the text doesn't fit in N bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 20, 300];
+    /// let text = vec.to_fixed_string::<16>(", ").unwrap();
+    /// assert_eq!(text.as_slice(), b"1, 20, 300");
+    /// ```
+    ///
+    pub fn to_fixed_string<const N: usize>(&self, sep: &str) -> Result<VecArray<u8, N>, ArrTooSmall>
+    where
+        T: fmt::Display,
+    {
+        use fmt::Write as _;
+
+        let mut out = VecArray::new();
+        let mut writer = ByteWriter(&mut out);
+        for (i, x) in self.as_slice().iter().enumerate() {
+            if i > 0 {
+                writer.write_str(sep).map_err(|_| ArrTooSmall)?;
+            }
+            write!(writer, "{x}").map_err(|_| ArrTooSmall)?;
+        }
+        Ok(out)
+    }
+
+    /// Splits the elements into two halves, the second one gets the extra element if the length is odd.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.halve(), (&[1][..], &[2, 3][..]));
+    /// ```
+    ///
+    pub fn halve(&self) -> (&[T], &[T]) {
+        self.as_slice().split_at(self.len / 2)
+    }
+
+    /// Treats the vec as a row-major `rows` x `cols` matrix and returns its transpose,
+    /// returns an error if `rows * cols` isn't the length.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    /// assert_eq!(vec.transpose_matrix(2, 2).unwrap(), vec![1, 3, 2, 4]);
+    /// ```
+    ///
+    #[allow(clippy::result_unit_err)]
+    pub fn transpose_matrix(&self, rows: usize, cols: usize) -> Result<VecArray<T, CAP>, ()>
+    where
+        T: Copy,
+    {
+        if rows.checked_mul(cols) != Some(self.len) {
+            return Err(());
+        }
+
+        let slice = self.as_slice();
+        let mut out = Self::new();
+        for c in 0..cols {
+            for r in 0..rows {
+                // infallible
+                let _ = out.push(slice[r * cols + c]);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the number of elements at the start of the vec for which `pred` returns true.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![' ', ' ', 'a', ' '];
+    /// assert_eq!(vec.leading_count(|c| c.is_whitespace()), 2);
+    /// ```
+    ///
+    pub fn leading_count<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.as_slice().iter().take_while(|x| pred(x)).count()
+    }
+
+    /// Returns the number of elements at the end of the vec for which `pred` returns true.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![' ', 'a', ' ', ' '];
+    /// assert_eq!(vec.trailing_count(|c| c.is_whitespace()), 2);
+    /// ```
+    ///
+    pub fn trailing_count<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.as_slice().iter().rev().take_while(|x| pred(x)).count()
+    }
+
+    /// Returns the elements in a Vec with a clone of `sep` between each pair of adjacent elements.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr!["a", "b"];
+    /// assert_eq!(vec.intersperse("-"), vec!["a", "-", "b"]);
+    /// ```
+    ///
+    pub fn intersperse(&self, sep: T) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut out = Vec::with_capacity((self.len * 2).saturating_sub(1));
+        for (i, x) in self.as_slice().iter().enumerate() {
+            if i > 0 {
+                out.push(sep.clone());
+            }
+            out.push(x.clone());
+        }
+        out
+    }
+
+    /// Returns the number of leading elements that are equal in both vecs.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let a: VecArray<_, 10> = vec_arr!['a', 'b', 'c'];
+    /// let b: VecArray<_, 10> = vec_arr!['a', 'b', 'd'];
+    /// assert_eq!(a.common_prefix_len(&b), 2);
+    /// ```
+    ///
+    pub fn common_prefix_len(&self, other: &Self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Sorts the vec, the sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    /// vec.sort();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort();
+    }
+
+    /// Sorts the vec without preserving the order of equal elements.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    /// vec.sort_unstable();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable();
+    }
+
+    /// Sorts the vec with a comparator function, the sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    /// vec.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, vec![3, 2, 1]);
+    /// ```
+    ///
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Sorts the vec with a comparator function without preserving the order of equal elements.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    /// vec.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec, vec![3, 2, 1]);
+    /// ```
+    ///
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.as_mut_slice().sort_unstable_by(compare);
+    }
+
+    /// Sorts the vec with a key extraction function, the sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![-3, 1, -2];
+    /// vec.sort_by_key(|x: &i32| x.abs());
+    /// assert_eq!(vec, vec![1, -2, -3]);
+    /// ```
+    ///
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.as_mut_slice().sort_by_key(f);
+    }
+
+    /// Removes all elements equal to `sentinel` from the end of the vec, returns how many were removed.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<u8, 10> = vec_arr![b'o', b'k', 0, 0, 0];
+    /// assert_eq!(vec.trim_trailing(0), 3);
+    /// assert_eq!(vec.as_slice(), b"ok");
+    /// ```
+    ///
+    pub fn trim_trailing(&mut self, sentinel: T) -> usize
+    where
+        T: PartialEq,
+    {
+        let count = self.trailing_count(|x| *x == sentinel);
+        self.truncate(self.len - count);
+        count
+    }
+
+    /// Binary searches the sorted vec for `x`, returns `Ok` with its index if found
+    /// or `Err` with the index where it could be inserted to keep the vec sorted.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 3, 5];
+    /// assert_eq!(vec.binary_search(&3), Ok(1));
+    /// assert_eq!(vec.binary_search(&4), Err(2));
+    /// ```
+    ///
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    /// Binary searches the sorted vec with a comparator function, see binary_search.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![5, 3, 1];
+    /// assert_eq!(vec.binary_search_by(|x| 3.cmp(x)), Ok(1));
+    /// ```
+    ///
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Binary searches the vec sorted by a key with a key extraction function, see binary_search.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![(1, 'a'), (2, 'b'), (4, 'c')];
+    /// assert_eq!(vec.binary_search_by_key(&2, |&(x, _)| x), Ok(1));
+    /// assert_eq!(vec.binary_search_by_key(&3, |&(x, _)| x), Err(2));
+    /// ```
+    ///
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.as_slice().binary_search_by_key(b, f)
+    }
+
+    /// Returns the element at `index % len`, treating the vec as circular. None if the vec is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.get_wrapping(4), Some(&2));
+    /// ```
+    ///
+    pub fn get_wrapping(&self, index: usize) -> Option<&T> {
+        if self.len == 0 {
             None
         } else {
-            Some(&self.arr[index])
+            self.get(index % self.len)
         }
     }
 
@@ -357,23 +2449,30 @@ impl<T, const CAP: usize> VecArray<T, CAP> {
         if index >= self.len {
             Err(ArrTooSmall)
         } else {
-            self.arr[index] = value;
+            self.as_mut_slice()[index] = value;
             Ok(())
         }
     }
 
+    /// Shortens the vec to `len` elements and drops the rest, does nothing if `len` is greater than length.
+    ///
     pub fn truncate(&mut self, len: usize) {
         if len > self.len {
             return;
         }
+        let tail = &mut self.arr[len..self.len] as *mut [MaybeUninit<T>] as *mut [T];
+        // set first so a panicking drop can't lead to a double drop
         self.len = len;
+        unsafe {
+            ::std::ptr::drop_in_place(tail);
+        }
     }
 
     pub fn last(&self) -> Option<&T> {
         if self.len == 0 {
             None
         } else {
-            Some(&self.arr[self.len - 1])
+            Some(&self.as_slice()[self.len - 1])
         }
     }
 
@@ -381,82 +2480,543 @@ impl<T, const CAP: usize> VecArray<T, CAP> {
         if self.len == 0 {
             None
         } else {
-            Some(&self.arr[0])
+            Some(&self.as_slice()[0])
         }
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    /// Returns true if the vec contains an element equal to `x`.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert!(vec.contains(&2));
+    /// assert!(!vec.contains(&4));
+    /// ```
+    ///
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    /// Returns true if `needle` is a prefix of the vec.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert!(vec.starts_with(&[1, 2]));
+    /// assert!(!vec.starts_with(&[2, 3]));
+    /// ```
+    ///
+    pub fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().starts_with(needle)
+    }
+
+    /// Returns true if `needle` is a suffix of the vec.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert!(vec.ends_with(&[2, 3]));
+    /// assert!(!vec.ends_with(&[1, 2]));
+    /// ```
+    ///
+    pub fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().ends_with(needle)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
-            arr: &self.arr[..self.len],
+            arr: self.as_slice(),
             itr: 0,
+            end: self.len,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Iterates over copies of the elements, leaving the vec untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.iter_copied().sum::<i32>(), 6);
+    /// ```
+    ///
+    pub fn iter_copied(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        self.as_slice().iter().copied()
+    }
+
+    /// Iterates mutably from the back, yielding each element together with its index.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let mut iter = vec.iter_mut_indexed_rev();
+    /// assert_eq!(iter.next(), Some((2, &mut 3)));
+    /// ```
+    ///
+    pub fn iter_mut_indexed_rev(&mut self) -> impl Iterator<Item = (usize, &mut T)> + '_ {
+        self.as_mut_slice().iter_mut().enumerate().rev()
+    }
+
+    /// Iterates over the elements of this vec followed by the elements of `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let a: VecArray<_, 10> = vec_arr![1, 2];
+    /// let b: VecArray<_, 5> = vec_arr![3];
+    /// assert_eq!(a.chain(&b).collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    ///
+    pub fn chain<'a, const CAP2: usize>(
+        &'a self,
+        other: &'a VecArray<T, CAP2>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.as_slice().iter().chain(other.as_slice())
+    }
+
+    /// Iterates over the differences between consecutive elements (`curr - prev`),
+    /// yields one element less than the length.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 3, 6, 10];
+    /// assert_eq!(vec.deltas().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// ```
+    ///
+    pub fn deltas(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        self.as_slice().windows(2).map(|w| w[1] - w[0])
+    }
+
+    /// Iterates over all combinations of `K` elements, in lexicographic order of their indices.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let pairs: Vec<_> = vec.combinations::<2>().collect();
+    /// assert_eq!(pairs, vec![[&1, &2], [&1, &3], [&2, &3]]);
+    /// ```
+    ///
+    pub fn combinations<const K: usize>(&self) -> impl Iterator<Item = [&T; K]> + '_ {
+        let slice = self.as_slice();
+        let len = slice.len();
+        let mut indices: [usize; K] = ::std::array::from_fn(|i| i);
+        let mut done = K > len;
+
+        ::std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let ret = ::std::array::from_fn(|i| &slice[indices[i]]);
+
+            // advance the rightmost index which still has room, reset everything after it
+            done = true;
+            for i in (0..K).rev() {
+                if indices[i] < len - K + i {
+                    indices[i] += 1;
+                    for j in i + 1..K {
+                        indices[j] = indices[j - 1] + 1;
+                    }
+                    done = false;
+                    break;
+                }
+            }
+            Some(ret)
+        })
+    }
+
+    /// Removes the elements in `range` and yields them.
+    ///
+    /// When the iterator is dropped the remaining drained elements are dropped and the tail is moved back.
+    /// If the iterator is leaked the elements from the start of the range onwards are forgotten.
+    ///
+    /// # Panics
+    /// Panics if the start of the range is greater than the end or the end is greater than len.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    /// assert_eq!(vec.drain(1..3).collect::<Vec<_>>(), vec![2, 3]);
+    /// assert_eq!(vec, vec![1, 4, 5]);
+    /// ```
+    ///
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, CAP> {
+        let start = match range.start_bound() {
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x
+                .checked_add(1)
+                .expect("attempted to drain from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&x) => x
+                .checked_add(1)
+                .expect("attempted to drain up to maximum usize"),
+            Bound::Excluded(&x) => x,
+            Bound::Unbounded => self.len,
+        };
+        if start > end {
+            panic!("drain start (is {start}) should be <= end (is {end})");
+        }
+        if end > self.len {
+            panic!("drain end (is {end}) should be <= len (is {})", self.len);
+        }
+
+        let old_len = ::std::mem::replace(&mut self.len, start);
+        Drain {
+            vec: self,
+            start,
+            itr: start,
+            end,
+            old_len,
         }
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
-        self.arr[..self.len].iter_mut()
+    /// Yields copies of all elements and removes them from the vec.
+    ///
+    /// The vec is empty once the iterator is exhausted, if it is dropped early the elements
+    /// which weren't yielded stay in the vec.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.drain_copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert!(vec.is_empty());
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.drain_copied().next(), Some(1));
+    /// assert_eq!(vec, vec![2, 3]);
+    /// ```
+    ///
+    pub fn drain_copied(&mut self) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        DrainCopied { vec: self, itr: 0 }
+    }
+
+    /// Yields the runs of consecutive equal elements as sub-slices.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr!['a', 'a', 'b', 'a'];
+    /// let runs: Vec<&[char]> = vec.runs().collect();
+    /// assert_eq!(runs, vec![&['a', 'a'][..], &['b'][..], &['a'][..]]);
+    /// ```
+    ///
+    pub fn runs(&self) -> impl Iterator<Item = &[T]> + '_
+    where
+        T: PartialEq,
+    {
+        self.as_slice().chunk_by(|a, b| a == b)
     }
 
     #[inline]
     pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.arr.as_mut_ptr()
+        self.arr.as_mut_ptr() as *mut T
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.arr.as_ptr() as *const T
+    }
+
+    #[inline]
+    /// Returns the entire array
+    ///
+    /// # Safety
+    /// Can point to uninitialized memory, causes a segfault if memory is not properly initialized
+    ///
+    pub unsafe fn get_arr(self) -> [T; CAP] {
+        let slf = ManuallyDrop::new(self);
+        ::std::ptr::read(&slf.arr as *const [MaybeUninit<T>; CAP] as *const [T; CAP])
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the range of valid indices, `0..len`
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// for i in vec.indices() {
+    ///     assert_eq!(vec[i], i + 1);
+    /// }
+    /// ```
+    #[inline]
+    pub fn indices(&self) -> Range<usize> {
+        0..self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // the first len elements are initialized
+        unsafe { &*(&self.arr[..self.len] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // the first len elements are initialized
+        unsafe { &mut *(&mut self.arr[..self.len] as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Checks whether `additional` more elements fit, used by operations which add many elements at once.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    /// use vector_array::error::CapacityError;
+    ///
+    /// let vec: VecArray<_, 4> = vec_arr![1, 2, 3];
+    /// assert_eq!(vec.check_capacity(1), Ok(()));
+    /// assert_eq!(
+    ///     vec.check_capacity(2),
+    ///     Err(CapacityError { attempted: 5, capacity: 4 })
+    /// );
+    /// ```
+    ///
+    pub fn check_capacity(&self, additional: usize) -> Result<(), CapacityError> {
+        match self.len.checked_add(additional) {
+            Some(attempted) if attempted <= CAP => Ok(()),
+            attempted => Err(CapacityError {
+                attempted: attempted.unwrap_or(usize::MAX),
+                capacity: CAP,
+            }),
+        }
+    }
+
+    /// Checks the internal invariants, currently that the length doesn't exceed CAP.
+    ///
+    /// Can't check that the elements are initialized, but catches a corrupted length after
+    /// building a vec with ::from_parts.
+    ///
+    /// # Panics
+    /// If length is greater than CAP
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::vec::VecArray;
+    ///
+    /// let vec = VecArray::new_arr([1, 2, 3], 2);
+    /// vec.assert_valid();
+    /// ```
+    ///
+    pub fn assert_valid(&self) {
+        if self.len > CAP {
+            panic!("Length (is {}) should be <= CAP (is {CAP})", self.len);
+        }
     }
+}
 
-    #[inline]
-    pub fn as_ptr(&self) -> *const T {
-        self.arr.as_ptr()
+impl<const CAP: usize> VecArray<u8, CAP> {
+    /// Copies the bytes up to the first NUL, all bytes if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
+    ///
+    /// let vec = VecArray::from_cstr_bytes(b"hi\0\0\0");
+    /// assert_eq!(vec.as_slice(), b"hi");
+    /// assert_eq!(vec.capacity(), 5);
+    /// ```
+    ///
+    pub fn from_cstr_bytes(arr: &[u8; CAP]) -> Self {
+        let len = arr.iter().position(|&b| b == 0).unwrap_or(CAP);
+        let mut slf = Self::new();
+        // infallible
+        let _ = slf.extend_from_slice(&arr[..len]);
+        slf
     }
+}
 
-    #[inline]
-    /// Returns the entire array
+impl<const CAP: usize> VecArray<bool, CAP> {
+    /// Returns the number of true elements.
     ///
-    /// # Safety
-    /// Can point to uninitialized memory, causes a segfault if memory is not properly initialized
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
     ///
-    pub unsafe fn get_arr(self) -> [T; CAP] {
-        self.arr
+    /// let vec: VecArray<_, 10> = vec_arr![true, false, true];
+    /// assert_eq!(vec.popcount(), 2);
+    /// ```
+    ///
+    pub fn popcount(&self) -> usize {
+        self.as_slice().iter().filter(|&&x| x).count()
     }
 
-    #[inline]
-    pub fn len(&self) -> usize {
-        self.len
-    }
+    /// Packs the elements into bits, element `i` is bit `i % 8` of byte `i / 8`.
+    /// Unused bits are zero.
+    ///
+    /// Fails to compile if BYTES is too small to hold CAP bits.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![true, false, true, true, false, false, false, false, true];
+    /// assert_eq!(vec.to_bitmask::<2>(), [0b1101, 0b1]);
+    /// ```
+    ///
+    pub fn to_bitmask<const BYTES: usize>(&self) -> [u8; BYTES] {
+        const {
+            assert!(
+                CAP.div_ceil(8) <= BYTES,
+                "Bitmask should have room for CAP bits"
+            )
+        };
 
-    #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+        let mut mask = [0; BYTES];
+        for (i, &x) in self.as_slice().iter().enumerate() {
+            mask[i / 8] |= (x as u8) << (i % 8);
+        }
+        mask
     }
+}
 
-    #[inline]
-    pub fn is_full(&self) -> bool {
-        self.len == CAP
+#[cfg(feature = "bytemuck")]
+impl<T, const CAP: usize> VecArray<T, CAP>
+where
+    T: bytemuck::Pod,
+{
+    /// Returns the bytes of the elements, the uninitialized part of the array is not included.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<u16, 10> = vec_arr![1, 2];
+    /// assert_eq!(vec.as_bytes().len(), 4);
+    /// ```
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
     }
 
-    #[inline]
-    pub fn as_slice(&self) -> &[T] {
-        &self.arr[..self.len]
-    }
+    /// Reinterprets `bytes` as elements, the inverse of ::as_bytes. The bytes don't need to be aligned.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
+    ///
+    /// let vec = VecArray::<u16, 10>::from_bytes(&[1, 0, 2, 0]).unwrap();
+    /// assert_eq!(vec, vec![u16::from_ne_bytes([1, 0]), u16::from_ne_bytes([2, 0])]);
+    /// ```
+    ///
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let size = ::std::mem::size_of::<T>();
+        if size == 0 {
+            return if bytes.is_empty() {
+                Ok(Self::new_no_default())
+            } else {
+                Err(FromBytesError::SizeMismatch)
+            };
+        }
+        if !bytes.len().is_multiple_of(size) {
+            return Err(FromBytesError::SizeMismatch);
+        }
 
-    #[inline]
-    pub fn as_mut_slice(&mut self) -> &mut [T] {
-        &mut self.arr[..self.len]
+        let mut slf = Self::new_no_default();
+        slf.check_capacity(bytes.len() / size)
+            .map_err(FromBytesError::TooLong)?;
+        for chunk in bytes.chunks_exact(size) {
+            // infallible
+            let _ = slf.push(bytemuck::pod_read_unaligned(chunk));
+        }
+        Ok(slf)
     }
+}
 
-    #[inline]
-    pub fn clear(&mut self) {
-        self.len = 0;
+impl<T, const CAP: usize> From<VecArray<T, CAP>> for Vec<T> {
+    fn from(val: VecArray<T, CAP>) -> Self {
+        val.into_iter().collect()
     }
+}
 
-    #[inline]
-    pub fn capacity(&self) -> usize {
-        CAP
+impl<T, const CAP: usize> Clone for VecArray<T, CAP>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut vec = Self::new();
+        for x in self.iter() {
+            // infallible
+            let _ = vec.push(x.clone());
+        }
+        vec
     }
 }
 
-impl<T, const CAP: usize> From<VecArray<T, CAP>> for Vec<T> {
-    fn from(val: VecArray<T, CAP>) -> Self {
-        let mut vec = Vec::from(val.arr);
-        vec.truncate(val.len);
-        vec
+impl<T, const CAP: usize> Drop for VecArray<T, CAP> {
+    /// Drops the first len elements, the rest of the array is never touched
+    fn drop(&mut self) {
+        unsafe {
+            ::std::ptr::drop_in_place(
+                &mut self.arr[..self.len] as *mut [MaybeUninit<T>] as *mut [T],
+            );
+        }
     }
 }
 
@@ -471,7 +3031,7 @@ impl<T, const CAP: usize> Index<usize> for VecArray<T, CAP> {
         if index >= self.len {
             panic!("Index too big");
         } else {
-            &self.arr[index]
+            &self.as_slice()[index]
         }
     }
 }
@@ -485,27 +3045,57 @@ impl<T, const CAP: usize> IndexMut<usize> for VecArray<T, CAP> {
         if index >= self.len {
             panic!("Index too big");
         } else {
-            &mut self.arr[index]
+            &mut self.as_mut_slice()[index]
         }
     }
 }
 
-impl<T, const CAP: usize> From<Vec<T>> for VecArray<T, CAP>
-where
-    T: Default,
-{
+impl<T, const CAP: usize> Deref for VecArray<T, CAP> {
+    type Target = [T];
+
+    /// Gives access to all slice methods.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// assert!(vec.contains(&3));
+    /// assert_eq!(vec.windows(2).count(), 2);
+    /// ```
+    ///
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const CAP: usize> DerefMut for VecArray<T, CAP> {
+    /// Gives access to all mutable slice methods.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    /// vec.sort();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const CAP: usize> From<Vec<T>> for VecArray<T, CAP> {
+    /// See [`VecArray::try_from_vec`] for the non panicking version.
+    ///
     /// # Panics
     /// If inputs length is greater than CAP
     ///
     fn from(value: Vec<T>) -> Self {
-        if value.len() > CAP {
-            panic!("Vector too long");
-        } else {
-            let mut slf = Self::new();
-            for x in value {
-                slf.push(x).unwrap();
-            }
-            slf
+        match Self::try_from_vec(value) {
+            Ok(slf) => slf,
+            Err(_) => panic!("Vector too long"),
         }
     }
 }
@@ -515,14 +3105,34 @@ impl<T, const CAP: usize> IntoIterator for VecArray<T, CAP> {
     type IntoIter = IntoIter<Self::Item, CAP>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let slf = ManuallyDrop::new(self);
         Self::IntoIter {
-            arr: self.arr,
-            len: self.len,
+            arr: unsafe { ::std::ptr::read(&slf.arr) },
+            len: slf.len,
             itr: 0,
         }
     }
 }
 
+impl<T, const CAP: usize> IntoIter<T, CAP> {
+    /// Returns the remaining elements which haven't been yielded yet.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    /// let mut iter = vec.into_iter();
+    /// iter.next();
+    /// assert_eq!(iter.as_slice(), &[2, 3]);
+    /// ```
+    ///
+    pub fn as_slice(&self) -> &[T] {
+        // elements in itr..len haven't been read out yet
+        unsafe { &*(&self.arr[self.itr..self.len] as *const [MaybeUninit<T>] as *const [T]) }
+    }
+}
+
 impl<T, const CAP: usize> Iterator for IntoIter<T, CAP> {
     type Item = T;
 
@@ -533,18 +3143,107 @@ impl<T, const CAP: usize> Iterator for IntoIter<T, CAP> {
         if self.itr >= self.len {
             None
         } else {
-            let ret = Some(unsafe { ::std::ptr::read(&self.arr[self.itr] as *const T) });
+            let ret = Some(unsafe { self.arr[self.itr].assume_init_read() });
             self.itr += 1;
             ret
         }
     }
+
+    /// Drops the skipped elements in place instead of yielding them one by one
+    ///
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let start = self.itr;
+        // advance first so a panicking drop can't lead to a double drop
+        self.itr += n.min(self.len - start);
+        unsafe {
+            ::std::ptr::drop_in_place(
+                &mut self.arr[start..self.itr] as *mut [MaybeUninit<T>] as *mut [T],
+            );
+        }
+        self.next()
+    }
+}
+
+impl<T, const CAP: usize> Drop for IntoIter<T, CAP> {
+    /// Drops the elements which haven't been yielded
+    fn drop(&mut self) {
+        unsafe {
+            ::std::ptr::drop_in_place(
+                &mut self.arr[self.itr..self.len] as *mut [MaybeUninit<T>] as *mut [T],
+            );
+        }
+    }
+}
+
+/// Iterator returned by drain_copied, removes the yielded elements when dropped
+struct DrainCopied<'a, T: Copy, const CAP: usize> {
+    vec: &'a mut VecArray<T, CAP>,
+    itr: usize,
+}
+
+impl<T: Copy, const CAP: usize> Iterator for DrainCopied<'_, T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.vec.as_slice().get(self.itr).copied();
+        if x.is_some() {
+            self.itr += 1;
+        }
+        x
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.vec.len - self.itr;
+        (len, Some(len))
+    }
+}
+
+impl<T: Copy, const CAP: usize> Drop for DrainCopied<'_, T, CAP> {
+    fn drop(&mut self) {
+        self.vec.as_mut_slice().copy_within(self.itr.., 0);
+        self.vec.len -= self.itr;
+    }
+}
+
+impl<T, const CAP: usize> Iterator for Drain<'_, T, CAP> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.itr < self.end {
+            self.itr += 1;
+            Some(unsafe { self.vec.arr[self.itr - 1].assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.itr;
+        (len, Some(len))
+    }
+}
+
+impl<T, const CAP: usize> Drop for Drain<'_, T, CAP> {
+    fn drop(&mut self) {
+        let tail_len = self.old_len - self.end;
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            let remaining =
+                ::std::ptr::slice_from_raw_parts_mut(ptr.add(self.itr), self.end - self.itr);
+            // skip the rest of the drain if the remaining elements panic on drop
+            self.itr = self.end;
+            ::std::ptr::drop_in_place(remaining);
+            ::std::ptr::copy(ptr.add(self.end), ptr.add(self.start), tail_len);
+        }
+        self.vec.len = self.start + tail_len;
+    }
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.itr >= self.arr.len() {
+        if self.itr >= self.end {
             None
         } else {
             let ret = Some(&self.arr[self.itr]);
@@ -552,14 +3251,39 @@ impl<'a, T> Iterator for Iter<'a, T> {
             ret
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.itr = self.itr.saturating_add(n).min(self.end);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.itr;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.itr >= self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(&self.arr[self.end])
+        }
+    }
 }
 
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
 impl<T, const CAP: usize> fmt::Debug for VecArray<T, CAP>
 where
     T: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let arr = &self.arr[..self.len];
+        let arr = self.as_slice();
         if f.alternate() {
             write!(f, "{arr:#?}")
         } else {
@@ -568,6 +3292,17 @@ where
     }
 }
 
+/// Writes formatted text into a byte vec, used by to_fixed_string
+struct ByteWriter<'a, const N: usize>(&'a mut VecArray<u8, N>);
+
+impl<const N: usize> fmt::Write for ByteWriter<'_, N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0
+            .extend_from_slice(s.as_bytes())
+            .map_err(|_| fmt::Error)
+    }
+}
+
 impl<T, const CAP: usize, const CAP2: usize> PartialEq<VecArray<T, CAP2>> for VecArray<T, CAP>
 where
     T: PartialEq,
@@ -576,7 +3311,7 @@ where
         if self.len != other.len {
             false
         } else {
-            self.arr[..self.len] == other.arr[..other.len]
+            self.as_slice() == other.as_slice()
         }
     }
 }
@@ -589,7 +3324,7 @@ where
         if self.len != other.len() {
             false
         } else {
-            &self.arr[..self.len] == *other
+            self.as_slice() == *other
         }
     }
 }
@@ -602,12 +3337,79 @@ where
         if self.len != other.len() {
             false
         } else {
-            &self.arr[..self.len] == other.as_slice()
+            self.as_slice() == other.as_slice()
+        }
+    }
+}
+
+impl<T, const CAP: usize> Eq for VecArray<T, CAP> where T: Eq {}
+
+impl<T, const CAP: usize, const CAP2: usize> PartialOrd<VecArray<T, CAP2>> for VecArray<T, CAP>
+where
+    T: PartialOrd,
+{
+    /// Compares lexicographically like slices do
+    fn partial_cmp(&self, other: &VecArray<T, CAP2>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T, const CAP: usize> Ord for VecArray<T, CAP>
+where
+    T: Ord,
+{
+    /// Compares lexicographically like slices do
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T, const CAP: usize> Hash for VecArray<T, CAP>
+where
+    T: Hash,
+{
+    /// Hashes the same as the slice of elements, so the capacity doesn't matter
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, const CAP: usize> Extend<T> for VecArray<T, CAP> {
+    /// Pushes every item of the iterator, see [`VecArray::try_extend`] for the non panicking version.
+    ///
+    /// # Panics
+    /// Panics if the vec runs out of capacity.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::{vec_arr, VecArray};
+    ///
+    /// let mut vec: VecArray<_, 10> = vec_arr![1];
+    /// vec.extend([2, 3]);
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x).expect("Iter too long for VecArray");
         }
     }
 }
 
 impl<T, const CAP: usize> FromIterator<T> for VecArray<T, CAP> {
+    /// Collects an iterator into a vec, see [`VecArray::try_from_iter`] for the non panicking version.
+    ///
+    /// # Panics
+    /// Panics if the iterator yields more than CAP items.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
+    ///
+    /// let vec: VecArray<_, 10> = (0..5).collect();
+    /// assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+    /// ```
+    ///
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut c = Self::new_no_default();
 
@@ -619,21 +3421,81 @@ impl<T, const CAP: usize> FromIterator<T> for VecArray<T, CAP> {
     }
 }
 
-impl<T, const CAP: usize, const CAP2: usize> From<[T; CAP2]> for VecArray<T, CAP>
+impl<T, const CAP: usize> TryFrom<&[T]> for VecArray<T, CAP>
 where
-    T: Default,
+    T: Clone,
 {
-    fn from(value: [T; CAP2]) -> Self {
+    type Error = ArrTooSmall;
+
+    /// Clones the elements of the slice into a vec, returns an error if the slice is longer than CAP.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
+    ///
+    /// let vec = VecArray::<_, 3>::try_from(&[1, 2, 3][..]).unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// assert!(VecArray::<_, 2>::try_from(&[1, 2, 3][..]).is_err());
+    /// ```
+    ///
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        let mut slf = Self::new();
+        slf.extend_from_slice(value)?;
+        Ok(slf)
+    }
+}
+
+impl<T, const CAP: usize, const N: usize> From<[T; N]> for VecArray<T, CAP> {
+    /// Moves the elements of the array into a vec.
+    ///
+    /// Fails to compile if N is greater than CAP.
+    ///
+    /// # Example
+    /// ```
+    /// use vector_array::VecArray;
+    ///
+    /// let vec: VecArray<_, 10> = VecArray::from([1, 2, 3]);
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use vector_array::VecArray;
+    ///
+    /// let vec: VecArray<_, 2> = VecArray::from([1, 2, 3]);
+    /// ```
+    ///
+    fn from(value: [T; N]) -> Self {
+        const { assert!(N <= CAP, "Array length should be <= VecArray capacity") };
+
+        let value = ManuallyDrop::new(value);
         let mut slf = Self::new();
-        for x in value {
-            slf.push(x).expect("Arr too long");
+        // the elements are moved out of the array, which is never dropped
+        unsafe {
+            ::std::ptr::copy_nonoverlapping(value.as_ptr(), slf.as_mut_ptr(), N);
         }
+        slf.len = N;
         slf
     }
 }
 
+/// Creates a full VecArray from a list of elements, usable in const and static items
+///
+/// # Example
+/// ```
+/// use vector_array::{const_vec_arr, VecArray};
+///
+/// static TABLE: VecArray<u8, 4> = const_vec_arr![1, 2, 3, 4];
+/// assert_eq!(TABLE, vec![1, 2, 3, 4]);
+/// ```
+#[macro_export]
+macro_rules! const_vec_arr {
+    ($($x:expr),* $(,)?) => {
+        $crate::vec::VecArray::from_const([$($x),*])
+    };
+}
+
 /// Creates a VecArray just like the vec! macro
-#[macro_export()]
+#[macro_export]
 macro_rules! vec_arr {
     () => { VecArray::new() };
     ($($x:expr),+ $(,)?) => {
@@ -1,9 +1,22 @@
-use crate::error::ArrTooSmall;
+// get(0) resolves to the slice method through Deref, the tests check it by index on purpose
+#![allow(clippy::get_first)]
+
+use crate::error::{ArrTooSmall, CapacityError, PushError};
 use crate::vec::VecArray;
-use crate::vec_arr;
+use crate::{const_vec_arr, vec_arr};
 
 // All test rely on that .push and ::new work
 
+/// Increments the shared counter when dropped
+#[derive(Debug)]
+struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
 #[test]
 fn pop() {
     let mut vec: VecArray<_, 10> = VecArray::new();
@@ -35,6 +48,24 @@ fn insert() {
     assert_eq!(vec, vec![0, 1, 1, 2, 3, 4, 5]);
 }
 
+#[test]
+fn insert_at_end() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    vec.insert(vec.len(), 4);
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+
+    let mut vec: VecArray<_, 10> = VecArray::new();
+    vec.insert(0, 1);
+    assert_eq!(vec, vec![1]);
+}
+
+#[test]
+#[should_panic]
+fn insert_out_of_bounds() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    vec.insert(4, 4);
+}
+
 #[test]
 fn swap_remove() {
     let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
@@ -45,6 +76,20 @@ fn swap_remove() {
     assert_eq!(vec, &[4, 1, 2][..])
 }
 
+#[test]
+fn swap_remove_middle() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    assert_eq!(vec.swap_remove(1), 1);
+    assert_eq!(vec, vec![0, 3, 2]);
+}
+
+#[test]
+#[should_panic]
+fn swap_remove_out_of_bounds() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    vec.swap_remove(4);
+}
+
 #[test]
 fn swap() {
     let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
@@ -52,6 +97,13 @@ fn swap() {
     assert_eq!(vec, vec![0, 1, 5, 3, 4, 2]);
 }
 
+#[test]
+fn swap_same_index() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
+    vec.swap(3, 3);
+    assert_eq!(vec, vec![0, 1, 2, 3, 4, 5]);
+}
+
 #[test]
 fn retain() {
     let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
@@ -92,7 +144,7 @@ fn index() {
 #[should_panic]
 fn index_panic() {
     let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
-    vec[6];
+    let _ = vec[6];
 }
 
 #[test]
@@ -127,3 +179,1457 @@ fn truncate() {
     assert_eq!(vec.len(), 6);
     assert_eq!(vec, vec![0, 1, 2, 3, 4, 5]);
 }
+
+#[test]
+fn partition_dedup() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 1, 2, 3, 3];
+    assert_eq!(vec.partition_dedup(), &[1, 2, 3]);
+    assert_eq!(vec.len(), 5);
+
+    let mut vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.partition_dedup().is_empty());
+}
+
+#[test]
+fn rotate_left_cached() {
+    for mid in 0..=6 {
+        let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
+        let mut expected: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
+        vec.rotate_left_cached(mid);
+        expected.as_mut_slice().rotate_left(mid);
+        assert_eq!(vec, expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn rotate_left_cached_panic() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.rotate_left_cached(4);
+}
+
+#[test]
+fn try_push_many() {
+    let mut vec: VecArray<_, 5> = vec_arr![0, 1];
+    assert_eq!(vec.try_push_many([2, 3]), Ok(()));
+    assert_eq!(vec.try_push_many(4..10), Err((1, 5)));
+    assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn iter_copied() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    assert_eq!(vec.iter_copied().sum::<i32>(), 10);
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn move_to_front() {
+    let mut vec: VecArray<_, 10> = vec_arr![5, 2, 7, 4, 1, 8];
+    assert_eq!(vec.move_to_front(|&x| x % 2 == 0), 3);
+    assert_eq!(vec, vec![2, 4, 8, 5, 7, 1]);
+}
+
+#[test]
+fn for_each_chunk() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5, 6];
+    let mut lens = Vec::new();
+    vec.for_each_chunk(3, |chunk| lens.push(chunk.len()));
+    assert_eq!(lens, vec![3, 3, 1]);
+}
+
+#[test]
+#[should_panic]
+fn for_each_chunk_panic() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.for_each_chunk(0, |_| {});
+}
+
+#[test]
+fn into_iter_as_slice() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.as_slice(), &[0, 1, 2, 3]);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.as_slice(), &[1, 2, 3]);
+    iter.by_ref().for_each(drop);
+    assert!(iter.as_slice().is_empty());
+}
+
+#[test]
+fn push_or() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Overflow { rejected: u32 },
+    }
+
+    let mut vec: VecArray<_, 2> = VecArray::new();
+    assert_eq!(vec.push_or(1, |x| Error::Overflow { rejected: x }), Ok(()));
+    assert_eq!(vec.push_or(2, |x| Error::Overflow { rejected: x }), Ok(()));
+    assert_eq!(
+        vec.push_or(3, |x| Error::Overflow { rejected: x }),
+        Err(Error::Overflow { rejected: 3 })
+    );
+    assert_eq!(vec, vec![1, 2]);
+}
+
+#[test]
+fn parts() {
+    let vec: VecArray<_, 10> = vec_arr![String::from("a"), String::from("b")];
+    let (arr, len) = vec.into_parts();
+    assert_eq!(len, 2);
+    assert_eq!(unsafe { arr[1].assume_init_ref() }, "b");
+    let vec: VecArray<_, 10> = unsafe { VecArray::from_parts(arr, len) };
+    assert_eq!(vec, vec![String::from("a"), String::from("b")]);
+}
+
+#[test]
+fn assert_valid() {
+    let vec: VecArray<u32, 10> = VecArray::new();
+    vec.assert_valid();
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "len should be <= CAP")]
+fn new_arr_len_past_capacity() {
+    let _ = VecArray::new_arr([0, 1, 2], 4);
+}
+
+#[test]
+fn new_arr_drops_tail() {
+    let drops = std::cell::Cell::new(0);
+    let vec = VecArray::new_arr(
+        [
+            DropCounter(&drops),
+            DropCounter(&drops),
+            DropCounter(&drops),
+        ],
+        1,
+    );
+    assert_eq!(drops.get(), 2);
+    drop(vec);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn fill_to_capacity() {
+    let mut vec: VecArray<_, 6> = vec_arr![0, 1, 2];
+    vec.fill_to_capacity(9);
+    assert!(vec.is_full());
+    assert_eq!(vec, vec![0, 1, 2, 9, 9, 9]);
+}
+
+#[test]
+fn iter_mut_indexed_rev() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4];
+    let mut indices = Vec::new();
+    for (i, x) in vec.iter_mut_indexed_rev() {
+        indices.push(i);
+        if i % 2 == 1 {
+            *x = -*x;
+        }
+    }
+    assert_eq!(indices, vec![4, 3, 2, 1, 0]);
+    assert_eq!(vec, vec![0, -1, 2, -3, 4]);
+}
+
+#[test]
+fn hamming_distance() {
+    let a: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    let b: VecArray<_, 10> = vec_arr![1, 2, 0, 4];
+    assert_eq!(a.hamming_distance(&b), Some(1));
+    assert_eq!(a.hamming_distance(&a), Some(0));
+
+    let c: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert_eq!(a.hamming_distance(&c), None);
+}
+
+#[test]
+fn try_collect_options() {
+    let vec = VecArray::<_, 3>::try_collect_options([Some(0), Some(1), Some(2)]);
+    assert_eq!(vec, Some(vec_arr![0, 1, 2]));
+
+    let vec = VecArray::<_, 3>::try_collect_options([Some(0), None, Some(2)]);
+    assert_eq!(vec, None);
+
+    let vec = VecArray::<_, 3>::try_collect_options([Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(vec, None);
+}
+
+#[test]
+fn rotate_to_value() {
+    let mut vec: VecArray<_, 10> = vec_arr![3, 4, 1, 2];
+    assert!(vec.rotate_to_value(&1));
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+
+    assert!(!vec.rotate_to_value(&5));
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn map_in_place() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    vec.map_in_place(|x| x * x);
+    assert_eq!(vec, vec![0, 1, 4, 9]);
+}
+
+#[test]
+fn replace_all() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 0, 2, 0];
+    assert_eq!(vec.replace_all(&0, &9), 3);
+    assert_eq!(vec, vec![9, 1, 9, 2, 9]);
+    assert_eq!(vec.replace_all(&0, &9), 0);
+}
+
+#[test]
+fn peek_n() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    assert_eq!(vec.peek_n(2), Some(&[2, 3][..]));
+    assert_eq!(vec.peek_n(0), Some(&[][..]));
+    assert_eq!(vec.peek_n(4), Some(&[0, 1, 2, 3][..]));
+    assert_eq!(vec.peek_n(5), None);
+    assert_eq!(vec.len(), 4);
+}
+
+#[test]
+fn check_capacity() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(vec.check_capacity(2), Ok(()));
+
+    let err = vec.check_capacity(5).unwrap_err();
+    assert_eq!(err.attempted, 13);
+    assert_eq!(err.capacity, 10);
+    assert_eq!(ArrTooSmall::from(err), ArrTooSmall);
+
+    let err = vec.check_capacity(usize::MAX).unwrap_err();
+    assert_eq!(
+        err,
+        CapacityError {
+            attempted: usize::MAX,
+            capacity: 10
+        }
+    );
+}
+
+#[test]
+fn chain() {
+    let a: VecArray<_, 10> = vec_arr![0, 1, 2];
+    let b: VecArray<_, 4> = vec_arr![3, 4];
+    assert_eq!(a.chain(&b).sum::<i32>(), 10);
+    assert_eq!(b.chain(&a).count(), 5);
+}
+
+#[test]
+fn push_tracking() {
+    let mut dropped = 0;
+    let mut vec: VecArray<_, 3> = VecArray::new();
+    for x in 0..5 {
+        vec.push_tracking(x, &mut dropped);
+    }
+    assert_eq!(dropped, 2);
+    assert_eq!(vec, vec![0, 1, 2]);
+}
+
+#[test]
+fn reshape() {
+    let vec: VecArray<_, 6> = vec_arr![0, 1, 2];
+    let wide: VecArray<_, 10> = vec.reshape();
+    assert_eq!(wide, vec![0, 1, 2]);
+    let narrow: VecArray<_, 3> = wide.reshape();
+    assert!(narrow.is_full());
+    assert_eq!(narrow, vec![0, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn reshape_panic() {
+    let vec: VecArray<_, 6> = vec_arr![0, 1, 2];
+    let _: VecArray<_, 2> = vec.reshape();
+}
+
+#[test]
+fn dot() {
+    let a: VecArray<_, 10> = vec_arr![1, 2, 3];
+    let b: VecArray<_, 10> = vec_arr![4, 5, 6];
+    assert_eq!(a.dot(&b), Some(32));
+
+    let c: VecArray<_, 10> = vec_arr![4, 5];
+    assert_eq!(a.dot(&c), None);
+
+    let empty: VecArray<f64, 10> = VecArray::new();
+    assert_eq!(empty.dot(&empty), Some(0.0));
+}
+
+#[test]
+fn scale() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    vec.scale(2);
+    assert_eq!(vec, vec![2, 4, 6]);
+
+    let mut vec: VecArray<_, 10> = vec_arr![1.0, -0.5];
+    vec.scale(-2.0);
+    assert_eq!(vec, vec![-2.0, 1.0]);
+}
+
+#[test]
+fn try_new_arr() {
+    let vec = VecArray::try_new_arr([0, 1, 2, 3], 4).unwrap();
+    assert_eq!(vec, vec![0, 1, 2, 3]);
+    let vec = VecArray::try_new_arr([0, 1, 2, 3], 0).unwrap();
+    assert!(vec.is_empty());
+    assert_eq!(VecArray::try_new_arr([0, 1, 2, 3], 5), Err(ArrTooSmall));
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn as_bytes() {
+    let vec: VecArray<u32, 4> = vec_arr![1, 2, 3];
+    let bytes = vec.as_bytes();
+    assert_eq!(bytes.len(), vec.len() * 4);
+    assert_eq!(&bytes[4..8], &2u32.to_ne_bytes());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn from_bytes() {
+    use crate::error::FromBytesError;
+
+    let vec: VecArray<u32, 4> = vec_arr![1, 2, 3];
+    let back = VecArray::<u32, 4>::from_bytes(vec.as_bytes()).unwrap();
+    assert_eq!(back, vec);
+
+    // unaligned
+    let mut bytes = [0u8; 9];
+    bytes[1..].copy_from_slice(VecArray::<u32, 2>::from(vec![7, 8]).as_bytes());
+    assert_eq!(
+        VecArray::<u32, 2>::from_bytes(&bytes[1..]).unwrap(),
+        vec![7, 8]
+    );
+
+    assert_eq!(
+        VecArray::<u32, 4>::from_bytes(&[0; 6]),
+        Err(FromBytesError::SizeMismatch)
+    );
+    assert_eq!(
+        VecArray::<u32, 1>::from_bytes(&[0; 8]),
+        Err(FromBytesError::TooLong(CapacityError {
+            attempted: 2,
+            capacity: 1
+        }))
+    );
+}
+
+#[test]
+fn mean_variance() {
+    let vec: VecArray<f64, 10> = vec_arr![2.0, 4.0, 6.0];
+    assert_eq!(vec.mean(), Some(4.0));
+    assert_eq!(vec.variance(), Some(8.0 / 3.0));
+
+    let vec: VecArray<u8, 10> = vec_arr![1, 1, 1];
+    assert_eq!(vec.mean(), Some(1.0));
+    assert_eq!(vec.variance(), Some(0.0));
+
+    let vec: VecArray<f32, 10> = VecArray::new();
+    assert_eq!(vec.mean(), None);
+    assert_eq!(vec.variance(), None);
+}
+
+#[test]
+fn shift_right() {
+    let mut vec: VecArray<_, 5> = vec_arr![1, 2, 3];
+    assert_eq!(vec.shift_right(2, 0), Ok(()));
+    assert_eq!(vec, vec![0, 0, 1, 2, 3]);
+    assert_eq!(vec.shift_right(1, 0), Err(ArrTooSmall));
+    assert_eq!(vec, vec![0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn push_overwrite() {
+    let mut vec: VecArray<_, 3> = VecArray::new();
+    let evicted: Vec<_> = (0..5).filter_map(|x| vec.push_overwrite(x)).collect();
+    assert_eq!(evicted, vec![0, 1]);
+    assert_eq!(vec, vec![2, 3, 4]);
+
+    let mut vec: VecArray<_, 0> = VecArray::new();
+    assert_eq!(vec.push_overwrite(1), Some(1));
+}
+
+#[test]
+fn longest_increasing_run() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 1, 2, 3, 4, 1];
+    assert_eq!(vec.longest_increasing_run(), 2..6);
+
+    let vec: VecArray<_, 10> = vec_arr![3, 3, 3];
+    assert_eq!(vec.longest_increasing_run(), 0..1);
+
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 0, 1];
+    assert_eq!(vec.longest_increasing_run(), 0..2);
+
+    let vec: VecArray<_, 10> = vec_arr![1.0, f64::NAN, 2.0, 3.0];
+    assert_eq!(vec.longest_increasing_run(), 2..4);
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert_eq!(vec.longest_increasing_run(), 0..0);
+}
+
+#[test]
+fn split_once() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 0, 3, 0];
+    assert_eq!(
+        vec.split_once(|&x| x == 0),
+        Some((&[1, 2][..], &[3, 0][..]))
+    );
+    assert_eq!(
+        vec.split_once(|&x| x == 1),
+        Some((&[][..], &[2, 0, 3, 0][..]))
+    );
+    assert_eq!(vec.split_once(|&x| x == 4), None);
+}
+
+#[test]
+fn deltas() {
+    let vec: VecArray<_, 10> = vec_arr![1, 3, 6, 10];
+    assert_eq!(vec.deltas().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+    let vec: VecArray<_, 10> = vec_arr![1];
+    assert_eq!(vec.deltas().count(), 0);
+}
+
+#[test]
+fn rotate_left_collecting() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4];
+    assert_eq!(vec.rotate_left_collecting(3), vec![0, 1, 2]);
+    assert_eq!(vec, vec![3, 4, 0, 1, 2]);
+    assert!(vec.rotate_left_collecting(0).is_empty());
+    assert_eq!(vec, vec![3, 4, 0, 1, 2]);
+}
+
+#[test]
+fn const_vec_arr() {
+    static TABLE: VecArray<u8, 4> = const_vec_arr![1, 2, 3, 4];
+    const EMPTY: VecArray<u8, 0> = const_vec_arr![];
+    assert!(TABLE.is_full());
+    assert_eq!(TABLE, vec![1, 2, 3, 4]);
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn gc() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5, 6];
+    assert_eq!(vec.gc(|&x| x % 3 == 0), 3);
+    assert_eq!(vec, vec![0, 3, 6]);
+    assert_eq!(vec.gc(|_| false), 0);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn combinations() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    let pairs: Vec<_> = vec.combinations::<2>().map(|[a, b]| (*a, *b)).collect();
+    assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+
+    assert_eq!(vec.combinations::<0>().count(), 1);
+    assert_eq!(vec.combinations::<4>().count(), 1);
+    assert_eq!(vec.combinations::<5>().count(), 0);
+}
+
+#[test]
+fn is_palindrome() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 1];
+    assert!(vec.is_palindrome());
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 2, 1];
+    assert!(vec.is_palindrome());
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert!(!vec.is_palindrome());
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.is_palindrome());
+}
+
+#[test]
+fn align_front() {
+    const SYNC: u8 = 0xAA;
+    let mut vec: VecArray<u8, 10> = vec_arr![1, 2, SYNC, 3, SYNC];
+    assert!(vec.align_front(|&b| b == SYNC));
+    assert_eq!(vec, vec![SYNC, 3, SYNC, 1, 2]);
+    assert!(!vec.align_front(|&b| b == 0));
+    assert_eq!(vec, vec![SYNC, 3, SYNC, 1, 2]);
+}
+
+#[test]
+fn copy_to() {
+    let src: VecArray<_, 10> = vec_arr![1, 2, 3];
+
+    let mut dst: VecArray<_, 3> = VecArray::new();
+    assert_eq!(src.copy_to(&mut dst), Ok(()));
+    assert_eq!(dst, vec![1, 2, 3]);
+
+    let mut dst: VecArray<_, 5> = vec_arr![0];
+    assert_eq!(src.copy_to(&mut dst), Ok(()));
+    assert_eq!(dst, vec![0, 1, 2, 3]);
+    assert_eq!(src.copy_to(&mut dst), Err(ArrTooSmall));
+    assert_eq!(dst, vec![0, 1, 2, 3]);
+    assert_eq!(src, vec![1, 2, 3]);
+}
+
+#[test]
+fn drain_copied() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    assert_eq!(vec.drain_copied().sum::<i32>(), 6);
+    assert!(vec.is_empty());
+
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    assert_eq!(vec.drain_copied().next(), Some(0));
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3];
+    let mut drain = vec.drain_copied();
+    assert_eq!(drain.nth(3), Some(3));
+    assert_eq!(drain.next(), None);
+    drop(drain);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn indices() {
+    let vec: VecArray<_, 10> = vec_arr![5, 6, 7];
+    assert_eq!(vec.indices(), 0..3);
+    let mut sum = 0;
+    for i in vec.indices() {
+        sum += vec[i];
+    }
+    assert_eq!(sum, 18);
+}
+
+#[test]
+fn move_element() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4];
+    vec.move_element(0, 3);
+    assert_eq!(vec, vec![1, 2, 3, 0, 4]);
+    vec.move_element(3, 0);
+    assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+    vec.move_element(2, 2);
+    assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn move_element_panic() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.move_element(0, 3);
+}
+
+#[test]
+fn count_distinct() {
+    let vec: VecArray<_, 10> = vec_arr![1, 1, 2, 3, 3];
+    assert_eq!(vec.count_distinct(), 3);
+    let vec: VecArray<_, 10> = vec_arr![3, 1, 3, 1];
+    assert_eq!(vec.count_distinct(), 2);
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert_eq!(vec.count_distinct(), 0);
+}
+
+#[test]
+fn frequencies() {
+    let vec: VecArray<_, 10> = vec_arr!["a", "b", "a", "c", "b", "a"];
+    assert_eq!(vec.frequencies(), vec![("a", 3), ("b", 2), ("c", 1)]);
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.frequencies().is_empty());
+}
+
+#[test]
+fn push_bounded() {
+    let mut vec: VecArray<_, 2> = VecArray::new();
+    assert_eq!(vec.push_bounded(3, &5), Ok(()));
+    assert_eq!(vec.push_bounded(5, &5), Ok(()));
+    assert_eq!(vec.push_bounded(6, &5), Err(PushError::OutOfBounds(6)));
+    assert_eq!(vec.push_bounded(4, &5), Err(PushError::Full(4)));
+    assert_eq!(vec.push_bounded(4, &5).unwrap_err().into_inner(), 4);
+    assert_eq!(vec, vec![3, 5]);
+}
+
+#[test]
+fn drop_elements() {
+    let drops = std::cell::Cell::new(0);
+    {
+        let mut vec: VecArray<_, 10> = VecArray::new_no_default();
+        for _ in 0..4 {
+            vec.push(DropCounter(&drops)).unwrap();
+        }
+    }
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn drop_strings() {
+    let mut vec: VecArray<String, 4> = VecArray::new_no_default();
+    vec.push(String::from("a")).unwrap();
+    vec.push(String::from("b")).unwrap();
+    vec.pop();
+    assert_eq!(vec, vec![String::from("a")]);
+}
+
+#[test]
+fn truncate_drops() {
+    let drops = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 10> = VecArray::new_no_default();
+    for _ in 0..5 {
+        vec.push(DropCounter(&drops)).unwrap();
+    }
+    vec.truncate(3);
+    assert_eq!(drops.get(), 2);
+    vec.clear();
+    assert_eq!(drops.get(), 5);
+    std::mem::drop(vec);
+    assert_eq!(drops.get(), 5);
+}
+
+#[test]
+fn merge_sorted() {
+    let a: VecArray<_, 3> = vec_arr![1, 3, 5];
+    let b: VecArray<_, 2> = vec_arr![2, 4];
+    let merged: VecArray<_, 5> = a.merge_sorted(b).unwrap();
+    assert_eq!(merged, vec![1, 2, 3, 4, 5]);
+
+    let a: VecArray<_, 3> = vec_arr![1, 3, 5];
+    let b: VecArray<_, 2> = vec_arr![2, 4];
+    assert_eq!(a.merge_sorted::<2, 4>(b), Err(ArrTooSmall));
+}
+
+#[test]
+fn uninit_storage() {
+    let mut bools: VecArray<bool, 8> = VecArray::new();
+    assert!(bools.is_empty());
+    bools.push(true).unwrap();
+    assert_eq!(bools, vec![true]);
+
+    let x = 7;
+    let mut refs: VecArray<&u32, 4> = VecArray::new();
+    assert!(refs.is_empty());
+    refs.push(&x).unwrap();
+    assert_eq!(refs.pop(), Some(&7));
+}
+
+#[test]
+fn clone() {
+    let vec: VecArray<String, 4> = vec_arr![String::from("a"), String::from("b")];
+    let cloned = vec.clone();
+    assert_eq!(cloned, vec);
+    assert_eq!(cloned.capacity(), 4);
+}
+
+#[test]
+fn running_max() {
+    let vec: VecArray<_, 10> = vec_arr![1, 3, 2, 5, 4];
+    assert_eq!(vec.running_max(), vec![1, 3, 3, 5, 5]);
+    let vec: VecArray<i32, 10> = VecArray::new();
+    assert!(vec.running_max().is_empty());
+}
+
+#[test]
+fn into_iter_drop() {
+    let drops = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 10> = VecArray::new();
+    for _ in 0..4 {
+        vec.push(DropCounter(&drops)).unwrap();
+    }
+
+    let mut iter = vec.into_iter();
+    let first = iter.next();
+    iter.next();
+    assert_eq!(drops.get(), 1);
+    std::mem::drop(iter);
+    assert_eq!(drops.get(), 3);
+    std::mem::drop(first);
+    assert_eq!(drops.get(), 4);
+}
+
+#[test]
+fn hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of(x: impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut map = HashMap::new();
+    let key: VecArray<i32, 8> = vec_arr![1, 2, 3];
+    map.insert(key, "value");
+
+    let mut lookup: VecArray<i32, 8> = vec_arr![1, 2, 3, 4];
+    lookup.pop();
+    assert_eq!(map.get(&lookup), Some(&"value"));
+
+    let other_cap: VecArray<i32, 4> = vec_arr![1, 2, 3];
+    assert_eq!(hash_of(&lookup), hash_of(&other_cap));
+    assert_eq!(hash_of(&lookup), hash_of(&[1, 2, 3][..]));
+}
+
+#[test]
+fn into_iter_nth() {
+    let drops = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 5> = VecArray::new();
+    for i in 0..5 {
+        vec.push((i, DropCounter(&drops))).unwrap();
+    }
+
+    let mut iter = vec.into_iter();
+    let third = iter.nth(2).unwrap();
+    assert_eq!(third.0, 2);
+    assert_eq!(drops.get(), 2);
+    assert_eq!(iter.as_slice().len(), 2);
+    assert!(iter.nth(5).is_none());
+    assert_eq!(drops.get(), 4);
+    std::mem::drop(iter);
+    std::mem::drop(third);
+    assert_eq!(drops.get(), 5);
+
+    let vec: VecArray<String, 5> = vec_arr![
+        String::from("a"),
+        String::from("b"),
+        String::from("c"),
+        String::from("d"),
+        String::from("e")
+    ];
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.nth(2), Some(String::from("c")));
+    assert_eq!(iter.next(), Some(String::from("d")));
+}
+
+#[test]
+fn iter_nth() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4];
+    let mut iter = vec.iter();
+    assert_eq!(iter.nth(2), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn ord() {
+    let a: VecArray<_, 10> = vec_arr![1, 2];
+    let b: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert!(a < b);
+
+    let a: VecArray<_, 10> = vec_arr![1, 3];
+    let b: VecArray<_, 4> = vec_arr![1, 2, 9];
+    assert!(a > b);
+
+    let mut c: VecArray<_, 10> = vec_arr![1, 2, 9, 9];
+    c.pop();
+    assert_eq!(c.partial_cmp(&b), Some(std::cmp::Ordering::Equal));
+
+    let set: std::collections::BTreeSet<VecArray<u8, 4>> = [
+        VecArray::from([2]),
+        VecArray::from([1, 5]),
+        VecArray::from([1]),
+    ]
+    .into_iter()
+    .collect();
+    let sorted: Vec<&[u8]> = set.iter().map(|x| x.as_slice()).collect();
+    assert_eq!(sorted, vec![&[1][..], &[1, 5], &[2]]);
+}
+
+#[test]
+fn get_wrapping() {
+    let vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    assert_eq!(vec.get_wrapping(1), Some(&1));
+    assert_eq!(vec.get_wrapping(3), Some(&0));
+    assert_eq!(vec.get_wrapping(8), Some(&2));
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert_eq!(vec.get_wrapping(0), None);
+}
+
+#[test]
+fn successors() {
+    let vec = VecArray::<u32, 6>::successors(1, |&x| Some(x * 2));
+    assert_eq!(vec, vec![1, 2, 4, 8, 16, 32]);
+
+    let vec = VecArray::<u32, 6>::successors(1, |&x| if x < 4 { Some(x * 2) } else { None });
+    assert_eq!(vec, vec![1, 2, 4]);
+
+    let vec = VecArray::<u32, 0>::successors(1, |&x| Some(x * 2));
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn try_from_iter() {
+    let vec = VecArray::<_, 5>::try_from_iter(0..5).unwrap();
+    assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+    assert_eq!(VecArray::<_, 5>::try_from_iter(0..6), Err(ArrTooSmall));
+
+    let vec: VecArray<_, 5> = (0..5).collect();
+    assert_eq!(vec, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn from_iter_too_long() {
+    let _: VecArray<_, 5> = (0..6).collect();
+}
+
+#[test]
+fn retain_valid() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, -2, 3, -4, 5];
+    let errors = vec.retain_valid(|&x| {
+        if x > 0 {
+            Ok(())
+        } else {
+            Err(format!("{x} is negative"))
+        }
+    });
+    assert_eq!(vec, vec![1, 3, 5]);
+    assert_eq!(errors, vec!["-2 is negative", "-4 is negative"]);
+}
+
+#[test]
+fn extend() {
+    let mut vec: VecArray<_, 3> = vec_arr![1];
+    vec.extend([2, 3]);
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let mut vec: VecArray<_, 3> = vec_arr![1];
+    assert_eq!(vec.try_extend([2, 3]), Ok(()));
+    assert_eq!(vec, vec![1, 2, 3]);
+    assert_eq!(vec.try_extend([4]), Err((0, 4)));
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let mut vec: VecArray<String, 2> = VecArray::new();
+    let err = vec.try_extend(["a", "b", "c", "d"].map(String::from));
+    assert_eq!(err, Err((2, String::from("c"))));
+    assert_eq!(vec.as_slice(), ["a", "b"]);
+}
+
+#[test]
+#[should_panic]
+fn extend_too_long() {
+    let mut vec: VecArray<_, 3> = vec_arr![1];
+    vec.extend([2, 3, 4]);
+}
+
+#[test]
+fn deref() {
+    let mut vec: VecArray<_, 10> = vec_arr![4, 2, 3, 1];
+    assert!(vec.contains(&3));
+    assert!(!vec.contains(&5));
+    assert_eq!(vec.windows(2).count(), 3);
+
+    vec.sort();
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+    vec.reverse();
+    assert_eq!(vec, vec![4, 3, 2, 1]);
+}
+
+#[test]
+fn deref_get_range() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert_eq!(vec.get(0..2), Some(&[1, 2][..]));
+    assert_eq!(vec.get(1..), Some(&[2, 3][..]));
+    assert_eq!(vec.get(2..4), None);
+}
+
+#[test]
+fn iter_double_ended() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    assert_eq!(
+        vec.iter().rev().copied().collect::<Vec<_>>(),
+        vec![4, 3, 2, 1]
+    );
+    assert_eq!(vec.iter().len(), 4);
+
+    let mut iter = vec.iter();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn pop_n() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    assert_eq!(vec.pop_n(2), vec![4, 5]);
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    assert_eq!(vec.pop_n(10), vec![1, 2, 3]);
+    assert!(vec.is_empty());
+    assert!(vec.pop_n(1).is_empty());
+
+    let counter = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 5> = VecArray::new();
+    for _ in 0..5 {
+        let _ = vec.push(DropCounter(&counter));
+    }
+    let popped = vec.pop_n(3);
+    assert_eq!(counter.get(), 0);
+    drop(popped);
+    assert_eq!(counter.get(), 3);
+    drop(vec);
+    assert_eq!(counter.get(), 5);
+}
+
+#[test]
+fn scatter() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    assert_eq!(vec.scatter(&[0, 2], &[10, 30]), Ok(()));
+    assert_eq!(vec, vec![10, 2, 30, 4]);
+
+    assert_eq!(vec.scatter(&[1, 4], &[20, 50]), Err(()));
+    assert_eq!(vec.scatter(&[1], &[20, 50]), Err(()));
+    assert_eq!(vec, vec![10, 2, 30, 4]);
+}
+
+#[test]
+fn drain() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2, 3, 4, 5];
+    assert_eq!(vec.drain(2..4).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(vec, vec![0, 1, 4, 5]);
+
+    assert_eq!(vec.drain(1..).collect::<Vec<_>>(), vec![1, 4, 5]);
+    assert_eq!(vec, vec![0]);
+
+    assert_eq!(vec.drain(..).collect::<Vec<_>>(), vec![0]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn drain_drop_unconsumed() {
+    let counter = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 10> = VecArray::new();
+    for _ in 0..6 {
+        let _ = vec.push(DropCounter(&counter));
+    }
+
+    let mut drain = vec.drain(1..5);
+    drop(drain.next());
+    assert_eq!(counter.get(), 1);
+    drop(drain);
+    assert_eq!(counter.get(), 4);
+    assert_eq!(vec.len(), 2);
+
+    std::mem::forget(vec.drain(..1));
+    assert_eq!(vec.len(), 0);
+    drop(vec);
+    assert_eq!(counter.get(), 4);
+}
+
+#[test]
+#[should_panic]
+fn drain_out_of_bounds() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.drain(1..4);
+}
+
+#[test]
+fn gather() {
+    let vec: VecArray<_, 10> = vec_arr![10, 20, 30];
+    assert_eq!(vec.gather(&[2, 0, 1]), Some(vec![30, 10, 20]));
+    assert_eq!(vec.gather(&[]), Some(vec![]));
+    assert_eq!(vec.gather(&[0, 3]), None);
+}
+
+#[test]
+fn all_equal() {
+    let vec: VecArray<_, 10> = vec_arr![5, 5, 5];
+    assert!(vec.all_equal());
+    let vec: VecArray<_, 10> = vec_arr![5, 6, 5];
+    assert!(!vec.all_equal());
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.all_equal());
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert_eq!(vec.extend_from_slice(&[4, 5]), Ok(()));
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+
+    let mut vec: VecArray<_, 4> = vec_arr![1, 2, 3];
+    assert_eq!(vec.extend_from_slice(&[4, 5]), Err(ArrTooSmall));
+    assert_eq!(vec, vec![1, 2, 3]);
+}
+
+#[test]
+fn zip_map() {
+    let a: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    let b: VecArray<_, 3> = vec_arr![10, 20, 30];
+    let sum: VecArray<_, 5> = a.zip_map(&b, |x, y| x + y);
+    assert_eq!(sum, vec![11, 22, 33]);
+    assert_eq!(a.len(), 4);
+}
+
+#[test]
+#[should_panic]
+fn zip_map_too_small() {
+    let a: VecArray<_, 10> = vec_arr![1, 2, 3];
+    let _: VecArray<i32, 2> = a.zip_map(&a, |x, y| x + y);
+}
+
+#[test]
+fn append() {
+    let mut a: VecArray<_, 10> = vec_arr![1, 2];
+    let mut b: VecArray<_, 5> = vec_arr![3, 4];
+    assert_eq!(a.append(&mut b), Ok(()));
+    assert_eq!(a, vec![1, 2, 3, 4]);
+    assert!(b.is_empty());
+
+    let mut a: VecArray<_, 3> = vec_arr![1, 2];
+    let mut b: VecArray<_, 5> = vec_arr![3, 4];
+    assert_eq!(a.append(&mut b), Err(ArrTooSmall));
+    assert_eq!(a, vec![1, 2]);
+    assert_eq!(b, vec![3, 4]);
+}
+
+#[test]
+fn push_unique() {
+    let mut vec: VecArray<_, 3> = vec_arr![1, 2];
+    assert_eq!(vec.push_unique(3), Ok(true));
+    assert_eq!(vec.push_unique(2), Ok(false));
+    assert_eq!(vec, vec![1, 2, 3]);
+    assert_eq!(vec.push_unique(1), Ok(false));
+    assert_eq!(vec.push_unique(4), Err(ArrTooSmall));
+}
+
+#[test]
+fn split_off() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    let tail = vec.split_off(2);
+    assert_eq!(vec, vec![1, 2]);
+    assert_eq!(tail, vec![3, 4, 5]);
+
+    assert!(vec.split_off(2).is_empty());
+    assert_eq!(vec.split_off(0), vec![1, 2]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn split_off_out_of_bounds() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    vec.split_off(4);
+}
+
+#[test]
+fn fold_bitwise() {
+    let vec: VecArray<u32, 10> = vec_arr![1, 2, 3];
+    assert_eq!(vec.fold_xor(), Some(0));
+    assert_eq!(vec.fold_or(), Some(3));
+    assert_eq!(vec.fold_and(), Some(0));
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert_eq!(vec.fold_xor(), None);
+    assert_eq!(vec.fold_or(), None);
+    assert_eq!(vec.fold_and(), None);
+}
+
+#[test]
+fn dedup() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 1, 2, 3, 3, 3, 4];
+    vec.dedup();
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+
+    let mut vec: VecArray<_, 10> = vec_arr![11, 12, 25, 31, 39, 14];
+    vec.dedup_by_key(|x| *x / 10);
+    assert_eq!(vec, vec![11, 25, 31, 14]);
+
+    let mut vec: VecArray<u32, 10> = VecArray::new();
+    vec.dedup();
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn dedup_drops_once() {
+    let counter = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 10> = VecArray::new();
+    for x in [1, 1, 2, 2, 2, 3] {
+        let _ = vec.push((x, DropCounter(&counter)));
+    }
+    vec.dedup_by_key(|(x, _)| *x);
+    assert_eq!(counter.get(), 3);
+    assert_eq!(vec.len(), 3);
+    drop(vec);
+    assert_eq!(counter.get(), 6);
+}
+
+#[test]
+fn to_fixed_string() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    let text = vec.to_fixed_string::<5>(",").unwrap();
+    assert_eq!(text.as_slice(), b"1,2,3");
+    assert_eq!(vec.to_fixed_string::<4>(","), Err(ArrTooSmall));
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.to_fixed_string::<0>(",").unwrap().is_empty());
+}
+
+#[test]
+fn contains_starts_ends_with() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert!(vec.contains(&2));
+    assert!(vec.starts_with(&[1, 2]));
+    assert!(vec.ends_with(&[2, 3]));
+    assert!(vec.ends_with(&[]));
+
+    // popped elements are no longer part of the vec
+    vec.pop();
+    assert!(!vec.contains(&3));
+    assert!(!vec.ends_with(&[2, 3]));
+    assert!(!vec.starts_with(&[1, 2, 3]));
+}
+
+#[test]
+fn halve() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    assert_eq!(vec.halve(), (&[1, 2][..], &[3, 4][..]));
+
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    assert_eq!(vec.halve(), (&[1, 2][..], &[3, 4, 5][..]));
+
+    let vec: VecArray<_, 10> = vec_arr![1];
+    assert_eq!(vec.halve(), (&[][..], &[1][..]));
+}
+
+#[test]
+fn fill() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    vec.fill(9);
+    assert_eq!(vec, vec![9, 9, 9]);
+    vec.fill_with(Default::default);
+    assert_eq!(vec, vec![0, 0, 0]);
+
+    let counter = std::cell::Cell::new(0);
+    let other = std::cell::Cell::new(0);
+    let mut vec: VecArray<_, 10> = VecArray::new();
+    for _ in 0..3 {
+        let _ = vec.push(DropCounter(&counter));
+    }
+    vec.fill_with(|| DropCounter(&other));
+    assert_eq!(counter.get(), 3);
+    assert_eq!(vec.len(), 3);
+}
+
+#[test]
+fn transpose_matrix() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5, 6];
+    let transposed = vec.transpose_matrix(2, 3).unwrap();
+    assert_eq!(transposed, vec![1, 4, 2, 5, 3, 6]);
+    assert_eq!(transposed.transpose_matrix(3, 2).unwrap(), vec);
+
+    assert_eq!(vec.transpose_matrix(2, 2), Err(()));
+    assert_eq!(vec.transpose_matrix(usize::MAX, 2), Err(()));
+}
+
+#[test]
+fn resize() {
+    let mut vec: VecArray<_, 10> = vec_arr![1];
+    vec.resize(3, 0);
+    assert_eq!(vec, vec![1, 0, 0]);
+    vec.resize(1, 0);
+    assert_eq!(vec, vec![1]);
+
+    vec.resize_with(3, || 7);
+    assert_eq!(vec, vec![1, 7, 7]);
+
+    assert_eq!(vec.try_resize(11, 0), Err(ArrTooSmall));
+    assert_eq!(vec, vec![1, 7, 7]);
+    assert_eq!(vec.try_resize(10, 0), Ok(()));
+    assert!(vec.is_full());
+}
+
+#[test]
+#[should_panic]
+fn resize_too_long() {
+    let mut vec: VecArray<_, 3> = vec_arr![1];
+    vec.resize(4, 0);
+}
+
+#[test]
+fn leading_trailing_count() {
+    let vec: VecArray<_, 10> = vec_arr![0, 0, 1, 2, 0];
+    assert_eq!(vec.leading_count(|&x| x == 0), 2);
+    assert_eq!(vec.trailing_count(|&x| x == 0), 1);
+    assert_eq!(vec.leading_count(|&x| x < 5), 5);
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert_eq!(vec.leading_count(|_| true), 0);
+    assert_eq!(vec.trailing_count(|_| true), 0);
+}
+
+#[test]
+fn try_insert() {
+    let mut vec: VecArray<_, 4> = vec_arr![1, 2];
+    assert_eq!(vec.try_insert(2, 4), Ok(()));
+    assert_eq!(vec.try_insert(2, 3), Ok(()));
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+
+    assert_eq!(vec.try_insert(4, 5), Err(ArrTooSmall));
+    assert_eq!(vec.try_insert(0, 0), Err(ArrTooSmall));
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn try_insert_out_of_bounds() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2];
+    let _ = vec.try_insert(3, 3);
+}
+
+#[test]
+#[should_panic]
+fn insert_len_past_capacity() {
+    let mut vec = VecArray::new_arr([1, 2, 3], 4);
+    vec.insert(4, 0);
+}
+
+#[test]
+#[should_panic]
+fn insert_full() {
+    let mut vec: VecArray<_, 2> = vec_arr![1, 2];
+    vec.insert(0, 0);
+}
+
+#[test]
+fn intersperse() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    assert_eq!(vec.intersperse(0), vec![1, 0, 2, 0, 3]);
+
+    let vec: VecArray<_, 10> = vec_arr![1];
+    assert_eq!(vec.intersperse(0), vec![1]);
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.intersperse(0).is_empty());
+}
+
+#[test]
+fn push_if() {
+    let mut vec: VecArray<_, 3> = vec_arr![1, 2];
+    assert_eq!(vec.push_if(0, |v| v.contains(&5)), Ok(false));
+    assert_eq!(vec.push_if(3, |v| v.last() == Some(&2)), Ok(true));
+    assert_eq!(vec, vec![1, 2, 3]);
+    assert_eq!(vec.push_if(4, |_| true), Err(ArrTooSmall));
+    assert_eq!(vec.push_if(4, |_| false), Ok(false));
+}
+
+#[test]
+fn rotate() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5];
+    vec.rotate_left(2);
+    assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+    vec.rotate_right(2);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+    vec.rotate_right(5);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn rotate_right_out_of_bounds() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3];
+    vec.rotate_right(4);
+}
+
+#[test]
+fn common_prefix_len() {
+    let a: VecArray<_, 10> = vec_arr![1, 2, 3, 9];
+    let b: VecArray<_, 10> = vec_arr![1, 2, 4];
+    assert_eq!(a.common_prefix_len(&b), 2);
+    assert_eq!(a.common_prefix_len(&a), 4);
+
+    let b: VecArray<_, 10> = vec_arr![1, 2];
+    assert_eq!(a.common_prefix_len(&b), 2);
+    assert_eq!(a.common_prefix_len(&VecArray::new()), 0);
+}
+
+#[test]
+fn sort() {
+    let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    vec.sort();
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let mut vec: VecArray<_, 10> = vec_arr![3, 1, 2];
+    vec.sort_unstable();
+    assert_eq!(vec, vec![1, 2, 3]);
+
+    let mut vec: VecArray<_, 10> = vec_arr![(1, 'a'), (3, 'b'), (1, 'c'), (2, 'd')];
+    vec.sort_by_key(|&(x, _)| std::cmp::Reverse(x));
+    assert_eq!(vec, vec![(3, 'b'), (2, 'd'), (1, 'a'), (1, 'c')]);
+
+    // the popped element must not be sorted back in
+    let mut vec: VecArray<_, 10> = vec_arr![3, 2, 0];
+    vec.pop();
+    vec.sort_unstable_by(|a, b| a.cmp(b));
+    assert_eq!(vec, vec![2, 3]);
+}
+
+#[test]
+fn trim_trailing() {
+    let mut vec: VecArray<u8, 10> = vec_arr![b'h', b'i', 0, 0];
+    assert_eq!(vec.trim_trailing(0), 2);
+    assert_eq!(vec.as_slice(), b"hi");
+    assert_eq!(vec.trim_trailing(0), 0);
+
+    let mut vec: VecArray<u8, 10> = vec_arr![0, 0];
+    assert_eq!(vec.trim_trailing(0), 2);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn binary_search() {
+    let vec: VecArray<_, 10> = vec_arr![1, 3, 5, 7];
+    assert_eq!(vec.binary_search(&5), Ok(2));
+    assert_eq!(vec.binary_search(&4), Err(2));
+    assert_eq!(vec.binary_search(&8), Err(4));
+    assert_eq!(vec.binary_search_by(|x| x.cmp(&7)), Ok(3));
+    assert_eq!(vec.binary_search_by_key(&0, |x| x / 2), Ok(0));
+    assert_eq!(vec.binary_search_by_key(&4, |x| x / 2), Err(4));
+}
+
+#[test]
+fn from_cstr_bytes() {
+    let vec = VecArray::from_cstr_bytes(b"abc\0def\0");
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.as_slice(), b"abc");
+
+    let vec = VecArray::from_cstr_bytes(b"abc");
+    assert_eq!(vec.as_slice(), b"abc");
+
+    let vec = VecArray::from_cstr_bytes(&[0; 4]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn from_array() {
+    let vec: VecArray<_, 10> = VecArray::from([1, 2, 3]);
+    assert_eq!(vec, vec![1, 2, 3]);
+    let vec: VecArray<_, 3> = [1, 2, 3].into();
+    assert!(vec.is_full());
+
+    // no Default bound, and every element is dropped once
+    let counter = std::cell::Cell::new(0);
+    let vec: VecArray<_, 5> = VecArray::from([DropCounter(&counter), DropCounter(&counter)]);
+    assert_eq!(counter.get(), 0);
+    drop(vec);
+    assert_eq!(counter.get(), 2);
+}
+
+#[test]
+fn try_from_slice() {
+    let slice: &[u32] = &[1, 2, 3, 4];
+    let vec = VecArray::<_, 4>::try_from(slice).unwrap();
+    assert_eq!(vec, vec![1, 2, 3, 4]);
+    assert_eq!(VecArray::<_, 3>::try_from(slice), Err(ArrTooSmall));
+}
+
+#[test]
+fn bitmask() {
+    let vec: VecArray<_, 16> =
+        vec_arr![true, true, false, true, false, false, false, false, false, true];
+    assert_eq!(vec.popcount(), 4);
+    assert_eq!(vec.to_bitmask::<2>(), [0b0000_1011, 0b0000_0010]);
+    assert_eq!(vec.to_bitmask::<3>(), [0b0000_1011, 0b0000_0010, 0]);
+
+    let vec: VecArray<bool, 8> = VecArray::new();
+    assert_eq!(vec.popcount(), 0);
+    assert_eq!(vec.to_bitmask::<1>(), [0]);
+}
+
+#[test]
+fn split_off_matching() {
+    let mut vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4, 5, 6, 7];
+    let even = vec.split_off_matching(|x| x % 2 == 0);
+    assert_eq!(even, vec![2, 4, 6]);
+    assert_eq!(vec, vec![1, 3, 5, 7]);
+
+    assert!(vec.split_off_matching(|_| false).is_empty());
+    assert_eq!(vec.split_off_matching(|_| true), vec![1, 3, 5, 7]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn try_from_vec() {
+    let vec = VecArray::<_, 4>::try_from_vec(vec![1, 2, 3]).unwrap();
+    assert_eq!(vec, vec![1, 2, 3]);
+    let vec = VecArray::<_, 2>::try_from_vec(vec![1, 2, 3]);
+    assert_eq!(vec, Err(vec![1, 2, 3]));
+
+    // no Default bound, and every element is dropped once
+    let counter = std::cell::Cell::new(0);
+    let vec: VecArray<_, 5> = VecArray::from(vec![DropCounter(&counter), DropCounter(&counter)]);
+    assert_eq!(counter.get(), 0);
+    drop(vec);
+    assert_eq!(counter.get(), 2);
+}
+
+#[test]
+fn prefix_product() {
+    let vec: VecArray<_, 10> = vec_arr![1, 2, 3, 4];
+    assert_eq!(vec.prefix_product(), vec![1, 2, 6, 24]);
+
+    let vec: VecArray<f64, 10> = vec_arr![0.5, 4.0];
+    assert_eq!(vec.prefix_product(), vec![0.5, 2.0]);
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert!(vec.prefix_product().is_empty());
+}
+
+#[test]
+fn const_new() {
+    static EMPTY: VecArray<u8, 16> = VecArray::new();
+    const ALSO_EMPTY: VecArray<String, 4> = VecArray::new_no_default();
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.capacity(), 16);
+    assert!(ALSO_EMPTY.is_empty());
+}
+
+#[test]
+fn runs() {
+    let vec: VecArray<_, 10> = vec_arr![1, 1, 2, 3, 3, 3];
+    let runs: Vec<&[i32]> = vec.runs().collect();
+    assert_eq!(runs, vec![&[1, 1][..], &[2][..], &[3, 3, 3][..]]);
+    assert_eq!(
+        vec.runs().map(|run| run.len()).collect::<Vec<_>>(),
+        vec![2, 1, 3]
+    );
+
+    let vec: VecArray<u32, 10> = VecArray::new();
+    assert_eq!(vec.runs().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "attempted to drain up to maximum usize")]
+fn drain_inclusive_max() {
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.drain(..=usize::MAX);
+}
+
+#[test]
+#[should_panic(expected = "attempted to drain from after maximum usize")]
+fn drain_excluded_start_max() {
+    use std::ops::Bound;
+    let mut vec: VecArray<_, 10> = vec_arr![0, 1, 2];
+    vec.drain((Bound::Excluded(usize::MAX), Bound::Unbounded));
+}
+
+#[test]
+fn move_to_front_owned() {
+    let mut vec: VecArray<String, 10> = ["a", "Bb", "c", "Dd", "Ee"].map(String::from).into();
+    assert_eq!(vec.move_to_front(|s| s.len() == 2), 3);
+    assert_eq!(vec.as_slice(), ["Bb", "Dd", "Ee", "a", "c"]);
+    assert_eq!(vec.move_to_front(|_| false), 0);
+    assert_eq!(vec.move_to_front(|_| true), 5);
+    assert_eq!(vec.as_slice(), ["Bb", "Dd", "Ee", "a", "c"]);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.move_to_front(|s| s == "a" || panic!())
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec.as_slice(), ["Bb", "Dd", "Ee", "a", "c"]);
+}